@@ -127,6 +127,7 @@ pub enum ImportDestination {
 }
 
 type Listener = Box<Fn(&[H256]) + Send + Sync>;
+type FullListener = Box<Fn(&[&PendingTransaction]) + Send + Sync>;
 
 /// Light transaction queue. See module docs for more details.
 #[derive(Default)]
@@ -134,6 +135,7 @@ pub struct TransactionQueue {
 	by_account: HashMap<Address, AccountTransactions>,
 	by_hash: H256FastMap<PendingTransaction>,
 	listeners: Vec<Listener>,
+	full_listeners: Vec<FullListener>,
 }
 
 impl fmt::Debug for TransactionQueue {
@@ -142,6 +144,7 @@ impl fmt::Debug for TransactionQueue {
 			.field("by_account", &self.by_account)
 			.field("by_hash", &self.by_hash)
 			.field("listeners", &self.listeners.len())
+			.field("full_listeners", &self.full_listeners.len())
 			.finish()
 	}
 }
@@ -253,6 +256,21 @@ impl TransactionQueue {
 	///
 	/// Returned transactions are batched by sender, in order of ascending nonce.
 	pub fn ready_transactions(&self, best_block_number: u64, best_block_timestamp: u64) -> Vec<PendingTransaction> {
+		self.ready_transactions_filtered(best_block_number, best_block_timestamp, usize::max_value(), |_| true)
+	}
+
+	/// Get up to `max_len` transactions ready to be propagated which match given filter.
+	///
+	/// The filter is applied while iterating the queue, so only returned transactions are cloned.
+	pub fn ready_transactions_filtered<F>(
+		&self,
+		best_block_number: u64,
+		best_block_timestamp: u64,
+		max_len: usize,
+		filter: F,
+	) -> Vec<PendingTransaction> where
+		F: Fn(&PendingTransaction) -> bool,
+	{
 		self.by_account.values()
 			.flat_map(|acct_txs| {
 				acct_txs.current.iter().take_while(|tx| match tx.condition {
@@ -262,13 +280,16 @@ impl TransactionQueue {
 				}).map(|info| info.hash)
 			})
 			.filter_map(|hash| match self.by_hash.get(&hash) {
-				Some(tx) => Some(tx.clone()),
+				Some(tx) => Some(tx),
 				None => {
 					warn!(target: "txqueue", "Inconsistency detected between `by_hash` and `by_account`: {} not stored.",
 						hash);
 					None
 				}
 			})
+			.filter(|tx| filter(tx))
+			.take(max_len)
+			.cloned()
 			.collect()
 	}
 
@@ -358,11 +379,27 @@ impl TransactionQueue {
 		self.listeners.push(f);
 	}
 
+	/// Add a transaction queue listener receiving full transactions.
+	pub fn add_full_listener(&mut self, f: FullListener) {
+		self.full_listeners.push(f);
+	}
+
 	/// Notifies all listeners about new pending transaction.
 	fn notify(&self, hashes: &[H256]) {
 		for listener in &self.listeners {
 			listener(hashes)
 		}
+
+		if self.full_listeners.is_empty() {
+			return;
+		}
+
+		let transactions = hashes.iter()
+			.filter_map(|hash| self.by_hash.get(hash))
+			.collect::<Vec<_>>();
+		for listener in &self.full_listeners {
+			listener(&transactions)
+		}
 	}
 }
 
@@ -388,6 +425,27 @@ mod tests {
 		assert!(txq.by_hash.is_empty());
 	}
 
+	#[test]
+	fn ready_transactions_filtered() {
+		let sender = Address::default();
+		let mut txq = TransactionQueue::default();
+
+		for i in 0..5 {
+			let mut tx = Transaction::default();
+			tx.nonce = i.into();
+			tx.value = i.into();
+
+			txq.import(tx.fake_sign(sender).into()).unwrap();
+		}
+
+		let odd = txq.ready_transactions_filtered(0, 0, usize::max_value(), |tx| tx.value.low_u64() % 2 == 1);
+		assert_eq!(odd.iter().map(|tx| tx.value.low_u64()).collect::<Vec<_>>(), vec![1, 3]);
+
+		// the limit applies to matching transactions
+		let limited = txq.ready_transactions_filtered(0, 0, 1, |tx| tx.value > 0.into());
+		assert_eq!(limited.iter().map(|tx| tx.value.low_u64()).collect::<Vec<_>>(), vec![1]);
+	}
+
 	#[test]
 	fn next_nonce() {
 		let sender = Address::default();
@@ -1541,8 +1541,9 @@ impl Call for Client {
 
 	fn estimate_gas(&self, t: &SignedTransaction, state: &Self::State, header: &Header) -> Result<U256, CallError> {
 		let (mut upper, max_upper, env_info) = {
-			let init = *header.gas_limit();
-			let max = init * U256::from(10);
+			// The gas of the transaction bounds the search, so callers can limit the work done here.
+			let max = cmp::min(*header.gas_limit() * U256::from(10), t.gas);
+			let init = cmp::min(*header.gas_limit(), max);
 
 			let env_info = EnvInfo {
 				number: header.number(),
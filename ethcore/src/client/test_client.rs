@@ -600,7 +600,11 @@ impl Call for TestBlockChainClient {
 		Ok(res)
 	}
 
-	fn estimate_gas(&self, _t: &SignedTransaction, _state: &Self::State, _header: &Header) -> Result<U256, CallError> {
+	fn estimate_gas(&self, t: &SignedTransaction, _state: &Self::State, _header: &Header) -> Result<U256, CallError> {
+		// the real search is bounded by the transaction gas
+		if t.gas < 21000.into() {
+			return Err(CallError::Exceptional(::vm::Error::OutOfGas));
+		}
 		Ok(21000.into())
 	}
 }
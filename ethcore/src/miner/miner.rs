@@ -263,6 +263,11 @@ impl Miner {
 		self.transaction_queue.add_listener(f);
 	}
 
+	/// Set a callback to be notified about imported transactions.
+	pub fn add_full_transactions_listener(&self, f: Box<Fn(&[Arc<VerifiedTransaction>]) + Send + Sync>) {
+		self.transaction_queue.add_full_listener(f);
+	}
+
 	/// Creates new instance of miner Arc.
 	pub fn new<A: LocalAccounts + 'static>(
 		options: MinerOptions,
@@ -1029,8 +1034,13 @@ impl miner::MinerService for Miner {
 		}
 	}
 
-	fn ready_transactions<C>(&self, chain: &C, max_len: usize, ordering: miner::PendingOrdering)
-		-> Vec<Arc<VerifiedTransaction>>
+	fn ready_transactions_filtered<C>(
+		&self,
+		chain: &C,
+		max_len: usize,
+		filter: Option<pool::TransactionFilter>,
+		ordering: miner::PendingOrdering,
+	) -> Vec<Arc<VerifiedTransaction>>
 	where
 		C: ChainInfo + Nonce + Sync,
 	{
@@ -1042,16 +1052,19 @@ impl miner::MinerService for Miner {
 			// those transactions are valid and will just be ready to be included in next block.
 			let nonce_cap = None;
 
-			self.transaction_queue.pending(
-				CachedNonceClient::new(chain, &self.nonce_cache),
-				pool::PendingSettings {
-					block_number: chain_info.best_block_number,
-					current_timestamp: chain_info.best_block_timestamp,
-					nonce_cap,
-					max_len,
-					ordering,
-				},
-			)
+			let client = CachedNonceClient::new(chain, &self.nonce_cache);
+			let settings = pool::PendingSettings {
+				block_number: chain_info.best_block_number,
+				current_timestamp: chain_info.best_block_timestamp,
+				nonce_cap,
+				max_len,
+				ordering,
+			};
+
+			match filter {
+				Some(ref filter) => self.transaction_queue.pending_filtered(client, settings, filter),
+				None => self.transaction_queue.pending(client, settings),
+			}
 		};
 
 		let from_pending = || {
@@ -1059,6 +1072,7 @@ impl miner::MinerService for Miner {
 				sealing.transactions
 					.iter()
 					.map(|signed| pool::VerifiedTransaction::from_pending_block_transaction(signed.clone()))
+					.filter(|tx| filter.as_ref().map_or(true, |filter| filter.matches(tx.signed())))
 					.map(Arc::new)
 					.take(max_len)
 					.collect()
@@ -33,7 +33,7 @@ use std::sync::Arc;
 use std::collections::{BTreeSet, BTreeMap};
 
 use bytes::Bytes;
-use ethcore_miner::pool::{VerifiedTransaction, QueueStatus, local_transactions};
+use ethcore_miner::pool::{VerifiedTransaction, QueueStatus, TransactionFilter, local_transactions};
 use ethereum_types::{H256, U256, Address};
 use types::transaction::{self, UnverifiedTransaction, SignedTransaction, PendingTransaction};
 use types::BlockNumber;
@@ -182,6 +182,21 @@ pub trait MinerService : Send + Sync {
 	/// If you don't need a full set of transactions, you can add `max_len` and create only a limited set of
 	/// transactions.
 	fn ready_transactions<C>(&self, chain: &C, max_len: usize, ordering: PendingOrdering) -> Vec<Arc<VerifiedTransaction>>
+		where C: ChainInfo + Nonce + Sync
+	{
+		self.ready_transactions_filtered(chain, max_len, None, ordering)
+	}
+
+	/// Get a list of ready transactions matching given filter, either ordered by priority or unordered (cheaper).
+	///
+	/// The filter is applied before `max_len`, so up to `max_len` matching transactions are returned.
+	fn ready_transactions_filtered<C>(
+		&self,
+		chain: &C,
+		max_len: usize,
+		filter: Option<TransactionFilter>,
+		ordering: PendingOrdering,
+	) -> Vec<Arc<VerifiedTransaction>>
 		where C: ChainInfo + Nonce + Sync;
 
 	/// Get a list of all transactions in the pool (some of them might not be ready for inclusion yet).
@@ -27,7 +27,7 @@ use types::filter::Filter;
 use types::view;
 use types::views::BlockView;
 
-use client::{BlockChainClient, Client, ClientConfig, BlockId, ChainInfo, BlockInfo, PrepareOpenBlock, ImportSealedBlock, ImportBlock};
+use client::{BlockChainClient, Call, Client, ClientConfig, BlockId, ChainInfo, BlockInfo, PrepareOpenBlock, ImportSealedBlock, ImportBlock};
 use ethereum;
 use executive::{Executive, TransactOptions};
 use miner::{Miner, PendingOrdering, MinerService};
@@ -366,3 +366,25 @@ fn transaction_proof() {
 	assert_eq!(state.balance(&Address::default()).unwrap(), 5.into());
 	assert_eq!(state.balance(&address).unwrap(), 95.into());
 }
+
+#[test]
+fn estimate_gas_is_bounded_by_transaction_gas() {
+	let client = generate_dummy_client(0);
+	let header = client.best_block_header();
+	let state = client.latest_state();
+	let transaction = |gas: u64| Transaction {
+		nonce: 0.into(),
+		gas_price: 0.into(),
+		gas: gas.into(),
+		action: Action::Call(Address::default()),
+		value: 0.into(),
+		data: Vec::new(),
+	}.fake_sign(Address::random());
+
+	// enough gas for a plain transfer
+	assert_eq!(client.estimate_gas(&transaction(30_000), &state, &header).unwrap(), 21_000.into());
+
+	// the search never goes above the transaction gas
+	let err = client.estimate_gas(&transaction(20_000), &state, &header).unwrap_err();
+	assert_eq!(format!("{}", err), "Transaction execution error (Requires higher than upper limit of 20000).");
+}
@@ -25,18 +25,22 @@ use txpool::{self, VerifiedTransaction};
 use pool::VerifiedTransaction as Transaction;
 
 type Listener = Box<Fn(&[H256]) + Send + Sync>;
+type FullListener = Box<Fn(&[Arc<Transaction>]) + Send + Sync>;
 
 /// Manages notifications to pending transaction listeners.
 #[derive(Default)]
 pub struct Notifier {
 	listeners: Vec<Listener>,
+	full_listeners: Vec<FullListener>,
 	pending: Vec<H256>,
+	pending_full: Vec<Arc<Transaction>>,
 }
 
 impl fmt::Debug for Notifier {
 	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
 		fmt.debug_struct("Notifier")
 			.field("listeners", &self.listeners.len())
+			.field("full_listeners", &self.full_listeners.len())
 			.field("pending", &self.pending)
 			.finish()
 	}
@@ -48,23 +52,36 @@ impl Notifier {
 		self.listeners.push(f)
 	}
 
+	/// Add new listener to receive notifications with full transactions.
+	pub fn add_full(&mut self, f: FullListener) {
+		self.full_listeners.push(f)
+	}
+
 	/// Notify listeners about all currently pending transactions.
 	pub fn notify(&mut self) {
-		if self.pending.is_empty() {
-			return;
+		if !self.pending.is_empty() {
+			for l in &self.listeners {
+				(l)(&self.pending);
+			}
+			self.pending.clear();
 		}
 
-		for l in &self.listeners {
-			(l)(&self.pending);
+		if !self.pending_full.is_empty() {
+			for l in &self.full_listeners {
+				(l)(&self.pending_full);
+			}
+			self.pending_full.clear();
 		}
-
-		self.pending.clear();
 	}
 }
 
 impl txpool::Listener<Transaction> for Notifier {
 	fn added(&mut self, tx: &Arc<Transaction>, _old: Option<&Arc<Transaction>>) {
 		self.pending.push(*tx.hash());
+		// Only keep the transactions around if anyone is interested in them.
+		if !self.full_listeners.is_empty() {
+			self.pending_full.push(tx.clone());
+		}
 	}
 }
 
@@ -148,6 +165,28 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn should_notify_full_listeners() {
+		// given
+		let received = Arc::new(Mutex::new(vec![]));
+		let r = received.clone();
+		let listener = Box::new(move |txs: &[Arc<Transaction>]| {
+			*r.lock() = txs.iter().map(|tx| *tx.hash()).collect();
+		});
+
+		let mut tx_listener = Notifier::default();
+		tx_listener.add_full(listener);
+
+		// when
+		let tx = new_tx();
+		tx_listener.added(&tx, None);
+		assert_eq!(*received.lock(), vec![]);
+
+		// then
+		tx_listener.notify();
+		assert_eq!(*received.lock(), vec![*tx.hash()]);
+	}
+
 	fn new_tx() -> Arc<Transaction> {
 		let signed = transaction::Transaction {
 			action: transaction::Action::Create,
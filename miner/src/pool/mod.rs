@@ -29,12 +29,14 @@ pub mod client;
 pub mod local_transactions;
 pub mod replace;
 pub mod scoring;
+pub mod transaction_filter;
 pub mod verifier;
 
 #[cfg(test)]
 mod tests;
 
 pub use self::queue::{TransactionQueue, Status as QueueStatus};
+pub use self::transaction_filter::{TransactionFilter, ValueRange};
 pub use self::txpool::{VerifiedTransaction as PoolVerifiedTransaction, Options};
 
 /// How to prioritize transactions in the pool
@@ -28,7 +28,7 @@ use types::transaction;
 
 use pool::{
 	self, replace, scoring, verifier, client, ready, listener,
-	PrioritizationStrategy, PendingOrdering, PendingSettings,
+	PrioritizationStrategy, PendingOrdering, PendingSettings, TransactionFilter,
 };
 use pool::local_transactions::LocalTransactionsList;
 
@@ -382,6 +382,34 @@ impl TransactionQueue {
 		pending
 	}
 
+	/// Returns current pending transactions matching given filter.
+	///
+	/// The filter is applied while iterating the pool, so `max_len` limits
+	/// the number of matching transactions returned.
+	/// NOTE: This always re-computes the pending set and bypasses the cache.
+	pub fn pending_filtered<C>(
+		&self,
+		client: C,
+		settings: PendingSettings,
+		filter: &TransactionFilter,
+	) -> Vec<Arc<pool::VerifiedTransaction>> where
+		C: client::NonceClient,
+	{
+		let PendingSettings { block_number, current_timestamp, nonce_cap, max_len, ordering } = settings;
+
+		if let PendingOrdering::Unordered = ordering {
+			let ready = Self::ready(client, block_number, current_timestamp, nonce_cap);
+			return self.pool.read().unordered_pending(ready)
+				.filter(|tx| filter.matches(tx.signed()))
+				.take(max_len)
+				.collect();
+		}
+
+		self.collect_pending(client, block_number, current_timestamp, nonce_cap, |i| {
+			i.filter(|tx| filter.matches(tx.signed())).take(max_len).collect()
+		})
+	}
+
 	/// Collect pending transactions.
 	///
 	/// NOTE This is re-computing the pending set and it might be expensive to do so.
@@ -574,6 +602,12 @@ impl TransactionQueue {
 		(pool.listener_mut().1).0.add(f);
 	}
 
+	/// Add a callback to be notified about all transactions entering the pool, with full transaction data.
+	pub fn add_full_listener(&self, f: Box<Fn(&[Arc<pool::VerifiedTransaction>]) + Send + Sync>) {
+		let mut pool = self.pool.write();
+		(pool.listener_mut().1).0.add_full(f);
+	}
+
 	/// Check if pending set is cached.
 	#[cfg(test)]
 	pub fn is_pending_cached(&self) -> bool {
@@ -18,7 +18,10 @@ use ethereum_types::U256;
 use types::transaction::{self, PendingTransaction};
 use txpool;
 
-use pool::{verifier, TransactionQueue, PrioritizationStrategy, PendingSettings, PendingOrdering};
+use pool::{
+	verifier, TransactionQueue, PrioritizationStrategy, PendingSettings, PendingOrdering,
+	TransactionFilter, ValueRange,
+};
 
 pub mod tx;
 pub mod client;
@@ -1074,3 +1077,68 @@ fn should_not_reject_early_in_case_gas_price_is_less_than_min_effective() {
 	assert_eq!(txq.status().status.transaction_count, 2);
 	assert!(client.was_verification_triggered());
 }
+
+#[test]
+fn should_return_pending_transactions_matching_filter() {
+	// given
+	let txq = new_queue();
+	let tx1 = Tx::gas_price(2).signed();
+	let tx2 = Tx::gas_price(5).signed();
+	let tx3 = Tx::gas_price(10).signed();
+	let (sender1, hash2) = (tx1.sender(), tx2.hash());
+	txq.import(TestClient::new(), vec![tx1, tx2, tx3].local());
+	assert_eq!(txq.status().status.transaction_count, 3);
+
+	// when
+	let by_gas_price = TransactionFilter {
+		gas_price: ValueRange { min: Some(3.into()), max: Some(5.into()) },
+		..Default::default()
+	};
+	let by_sender = TransactionFilter {
+		from: Some(sender1),
+		..Default::default()
+	};
+	let by_value = TransactionFilter {
+		value: ValueRange { min: Some(101.into()), max: None },
+		..Default::default()
+	};
+
+	// then
+	let settings = || PendingSettings::all_prioritized(0, 0);
+	let pending = txq.pending_filtered(TestClient::new(), settings(), &by_gas_price);
+	assert_eq!(pending.len(), 1);
+	assert_eq!(pending[0].hash, hash2);
+	let pending = txq.pending_filtered(TestClient::new(), settings(), &by_sender);
+	assert_eq!(pending.len(), 1);
+	assert_eq!(pending[0].sender, sender1);
+	assert!(txq.pending_filtered(TestClient::new(), settings(), &by_value).is_empty());
+	assert_eq!(txq.pending_filtered(TestClient::new(), settings(), &Default::default()).len(), 3);
+}
+
+#[test]
+fn should_apply_limit_after_filtering_pending_transactions() {
+	// given
+	let txq = new_queue();
+	let tx1 = Tx::gas_price(2).signed();
+	let tx2 = Tx::gas_price(5).signed();
+	let tx3 = Tx::gas_price(10).signed();
+	let hash1 = tx1.hash();
+	txq.import(TestClient::new(), vec![tx1, tx2, tx3].local());
+
+	// when
+	let filter = TransactionFilter {
+		gas_price: ValueRange { min: None, max: Some(2.into()) },
+		..Default::default()
+	};
+	let pending = txq.pending_filtered(TestClient::new(), PendingSettings {
+		block_number: 0,
+		current_timestamp: 0,
+		nonce_cap: None,
+		max_len: 1,
+		ordering: PendingOrdering::Priority,
+	}, &filter);
+
+	// then
+	assert_eq!(pending.len(), 1);
+	assert_eq!(pending[0].hash, hash1);
+}
@@ -0,0 +1,61 @@
+// Copyright 2015-2019 Parity Technologies (UK) Ltd.
+// This file is part of Parity Ethereum.
+
+// Parity Ethereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Ethereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Ethereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Filter for pending transactions.
+
+use ethereum_types::{U256, Address};
+use types::transaction::{Action, SignedTransaction};
+
+/// Inclusive range of `U256` values, unbounded on any side left unset.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ValueRange {
+	/// Lower bound (inclusive).
+	pub min: Option<U256>,
+	/// Upper bound (inclusive).
+	pub max: Option<U256>,
+}
+
+impl ValueRange {
+	fn contains(&self, value: &U256) -> bool {
+		self.min.as_ref().map_or(true, |min| value >= min)
+			&& self.max.as_ref().map_or(true, |max| value <= max)
+	}
+}
+
+/// Selects a subset of pending transactions.
+///
+/// Fields left unset match every transaction.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TransactionFilter {
+	/// Sender of the transaction.
+	pub from: Option<Address>,
+	/// Recipient of the transaction. Contract creations never match.
+	pub to: Option<Address>,
+	/// Accepted gas price range.
+	pub gas_price: ValueRange,
+	/// Accepted value range.
+	pub value: ValueRange,
+}
+
+impl TransactionFilter {
+	/// Returns true if given transaction passes the filter.
+	pub fn matches(&self, tx: &SignedTransaction) -> bool {
+		self.from.map_or(true, |from| tx.sender() == from)
+			&& self.to.map_or(true, |to| tx.action == Action::Call(to))
+			&& self.gas_price.contains(&tx.gas_price)
+			&& self.value.contains(&tx.value)
+	}
+}
@@ -526,6 +526,22 @@ usage! {
 			"--poll-lifetime=[S]",
 			"Set the RPC filter lifetime to S seconds. The filter has to be polled at least every S seconds , otherwise it is removed.",
 
+			ARG arg_jsonrpc_max_log_range: (u64) = 1000u64, or |c: &Config| c.rpc.as_ref()?.max_log_range.clone(),
+			"--jsonrpc-max-log-range=[BLOCKS]",
+			"Specify the maximum number of blocks a single eth_getLogs, eth_getFilterLogs or parity_getLogsNoTransactionHash query may span. Larger queries are rejected and have to be split into smaller ranges. Use 0 for no limit.",
+
+			ARG arg_jsonrpc_gas_cap: (u64) = 50000000u64, or |c: &Config| c.rpc.as_ref()?.gas_cap.clone(),
+			"--jsonrpc-gas-cap=[GAS]",
+			"Specify the maximum gas an eth_call or eth_estimateGas request may use. Calls without gas or requesting more are executed with this value and report an out of gas error if they need more. Use 0 for no cap.",
+
+			ARG arg_jsonrpc_max_batch_size: (usize) = 1000usize, or |c: &Config| c.rpc.as_ref()?.max_batch_size.clone(),
+			"--jsonrpc-max-batch-size=[CALLS]",
+			"Specify the maximum number of calls in a single JSON-RPC batch request, applies to all transports. Larger batches are rejected without processing any of their calls. Use 0 for no limit. The raw request size is limited separately by --jsonrpc-max-payload, which only applies to HTTP.",
+
+			ARG arg_jsonrpc_pending_transactions_rate_limit: (usize) = ::parity_rpc::v1::DEFAULT_PENDING_TRANSACTIONS_RATE_LIMIT, or |c: &Config| c.rpc.as_ref()?.pending_transactions_rate_limit,
+			"--jsonrpc-pending-transactions-rate-limit=[TXS]",
+			"Maximum number of full pending transactions pushed to a single pendingTransactions subscriber per second, applies to WebSockets and IPC subscriptions. Excess transactions are dropped. 0 means no limit.",
+
 		["API and Console Options – WebSockets"]
 			FLAG flag_no_ws: (bool) = false, or |c: &Config| c.websockets.as_ref()?.disable.clone(),
 			"--no-ws",
@@ -1264,6 +1280,10 @@ struct Rpc {
 	experimental_rpcs: Option<bool>,
 	poll_lifetime: Option<u32>,
 	allow_missing_blocks: Option<bool>,
+	max_log_range: Option<u64>,
+	gas_cap: Option<u64>,
+	max_batch_size: Option<usize>,
+	pending_transactions_rate_limit: Option<usize>,
 }
 
 #[derive(Default, Debug, PartialEq, Deserialize)]
@@ -1815,6 +1835,10 @@ mod tests {
 			arg_jsonrpc_threads: 4,
 			arg_jsonrpc_max_payload: None,
 			arg_poll_lifetime: 60u32,
+			arg_jsonrpc_max_log_range: 1000u64,
+			arg_jsonrpc_gas_cap: 50000000u64,
+			arg_jsonrpc_max_batch_size: 1000usize,
+			arg_jsonrpc_pending_transactions_rate_limit: 100usize,
 			flag_jsonrpc_allow_missing_blocks: false,
 
 			// WS
@@ -2096,7 +2120,11 @@ mod tests {
 				keep_alive: None,
 				experimental_rpcs: None,
 				poll_lifetime: None,
-				allow_missing_blocks: None
+				allow_missing_blocks: None,
+				max_log_range: None,
+				gas_cap: None,
+				max_batch_size: None,
+				pending_transactions_rate_limit: None,
 			}),
 			ipc: Some(Ipc {
 				disable: None,
@@ -376,6 +376,22 @@ impl Configuration {
 				miner_options: self.miner_options()?,
 				gas_price_percentile: self.args.arg_gas_price_percentile,
 				poll_lifetime: self.args.arg_poll_lifetime,
+				max_log_range_blocks: match self.args.arg_jsonrpc_max_log_range {
+					0 => None,
+					max => Some(max),
+				},
+				gas_cap: match self.args.arg_jsonrpc_gas_cap {
+					0 => None,
+					cap => Some(cap.into()),
+				},
+				max_batch_size: match self.args.arg_jsonrpc_max_batch_size {
+					0 => None,
+					max => Some(max),
+				},
+				pending_transactions_rate_limit: match self.args.arg_jsonrpc_pending_transactions_rate_limit {
+					0 => None,
+					limit => Some(limit),
+				},
 				ws_conf: ws_conf,
 				snapshot_conf: snapshot_conf,
 				http_conf: http_conf,
@@ -1424,6 +1440,10 @@ mod tests {
 			miner_options: Default::default(),
 			gas_price_percentile: 50,
 			poll_lifetime: 60,
+			max_log_range_blocks: Some(1000),
+			gas_cap: Some(50_000_000.into()),
+			max_batch_size: Some(1000),
+			pending_transactions_rate_limit: Some(100),
 			ws_conf: Default::default(),
 			http_conf: Default::default(),
 			ipc_conf: Default::default(),
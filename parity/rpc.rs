@@ -137,6 +137,7 @@ pub struct Dependencies<D: rpc_apis::Dependencies> {
 	pub apis: Arc<D>,
 	pub executor: Executor,
 	pub stats: Arc<RpcStats>,
+	pub max_batch_size: Option<usize>,
 }
 
 pub fn new_ws<D: rpc_apis::Dependencies>(
@@ -156,6 +157,7 @@ pub fn new_ws<D: rpc_apis::Dependencies>(
 		let mut handler = MetaIoHandler::with_middleware((
 			rpc::WsDispatcher::new(full_handler),
 			Middleware::new(deps.stats.clone(), deps.apis.activity_notifier())
+				.with_max_batch_size(deps.max_batch_size)
 		));
 		let apis = conf.apis.list_apis();
 		deps.apis.extend_with_set(&mut handler, &apis);
@@ -291,6 +293,7 @@ pub fn setup_apis<D>(apis: ApiSet, deps: &Dependencies<D>) -> MetaIoHandler<Meta
 {
 	let mut handler = MetaIoHandler::with_middleware(
 		Middleware::new(deps.stats.clone(), deps.apis.activity_notifier())
+			.with_max_batch_size(deps.max_batch_size)
 	);
 	let apis = apis.list_apis();
 	deps.apis.extend_with_set(&mut handler, &apis);
@@ -28,6 +28,7 @@ use ethcore::snapshot::SnapshotService;
 use ethcore_logger::RotatingLogger;
 use ethcore_private_tx::Provider as PrivateTransactionManager;
 use ethcore_service::PrivateTxService;
+use ethereum_types::U256;
 use hash_fetch::fetch::Client as FetchClient;
 use jsonrpc_core::{self as core, MetaIoHandler};
 use light::client::LightChainClient;
@@ -252,6 +253,9 @@ pub struct FullDependencies {
 	pub gas_price_percentile: usize,
 	pub poll_lifetime: u32,
 	pub allow_missing_blocks: bool,
+	pub max_log_range_blocks: Option<u64>,
+	pub gas_cap: Option<U256>,
+	pub pending_transactions_rate_limit: Option<usize>,
 }
 
 impl FullDependencies {
@@ -303,6 +307,8 @@ impl FullDependencies {
 							gas_price_percentile: self.gas_price_percentile,
 							allow_missing_blocks: self.allow_missing_blocks,
 							allow_experimental_rpcs: self.experimental_rpcs,
+							max_log_range_blocks: self.max_log_range_blocks,
+							gas_cap: self.gas_cap,
 						}
 					);
 					handler.extend_with(client.to_delegate());
@@ -312,6 +318,7 @@ impl FullDependencies {
 							self.client.clone(),
 							self.miner.clone(),
 							self.poll_lifetime,
+							self.max_log_range_blocks,
 						);
 						handler.extend_with(filter_client.to_delegate());
 
@@ -321,7 +328,8 @@ impl FullDependencies {
 				Api::EthPubSub => {
 					if !for_generic_pubsub {
 						let client =
-							EthPubSubClient::new(self.client.clone(), self.executor.clone());
+							EthPubSubClient::new(self.client.clone(), self.executor.clone())
+								.with_pending_transactions_rate_limit(self.pending_transactions_rate_limit);
 						let h = client.handler();
 						self.miner
 							.add_transactions_listener(Box::new(move |hashes| {
@@ -329,6 +337,13 @@ impl FullDependencies {
 									h.notify_new_transactions(hashes);
 								}
 							}));
+						let h = client.handler();
+						self.miner
+							.add_full_transactions_listener(Box::new(move |transactions| {
+								if let Some(h) = h.upgrade() {
+									h.notify_new_full_transactions(transactions.iter().map(|tx| tx.pending()));
+								}
+							}));
 
 						if let Some(h) = client.handler().upgrade() {
 							self.client.add_notify(h);
@@ -374,6 +389,7 @@ impl FullDependencies {
 							signer,
 							self.ws_address.clone(),
 							self.snapshot.clone().into(),
+							self.max_log_range_blocks,
 						).to_delegate(),
 					);
 					#[cfg(feature = "accounts")]
@@ -498,6 +514,9 @@ pub struct LightDependencies<T> {
 	pub private_tx_service: Option<Arc<PrivateTransactionManager>>,
 	pub gas_price_percentile: usize,
 	pub poll_lifetime: u32,
+	pub max_log_range_blocks: Option<u64>,
+	pub gas_cap: Option<U256>,
+	pub pending_transactions_rate_limit: Option<usize>,
 }
 
 impl<C: LightChainClient + 'static> LightDependencies<C> {
@@ -544,6 +563,8 @@ impl<C: LightChainClient + 'static> LightDependencies<C> {
 						self.cache.clone(),
 						self.gas_price_percentile,
 						self.poll_lifetime,
+						self.max_log_range_blocks,
+						self.gas_cap,
 					);
 					handler.extend_with(Eth::to_delegate(client.clone()));
 
@@ -560,7 +581,7 @@ impl<C: LightChainClient + 'static> LightDependencies<C> {
 						self.cache.clone(),
 						self.executor.clone(),
 						self.gas_price_percentile,
-					);
+					).with_pending_transactions_rate_limit(self.pending_transactions_rate_limit);
 					self.client.add_listener(client.handler() as Weak<_>);
 					let h = client.handler();
 					self.transaction_queue
@@ -570,6 +591,14 @@ impl<C: LightChainClient + 'static> LightDependencies<C> {
 								h.notify_new_transactions(transactions);
 							}
 						}));
+					let h = client.handler();
+					self.transaction_queue
+						.write()
+						.add_full_listener(Box::new(move |transactions| {
+							if let Some(h) = h.upgrade() {
+								h.notify_new_full_transactions(transactions.iter().cloned());
+							}
+						}));
 					handler.extend_with(EthPubSub::to_delegate(client));
 				}
 				Api::Personal => {
@@ -606,6 +635,7 @@ impl<C: LightChainClient + 'static> LightDependencies<C> {
 							signer,
 							self.ws_address.clone(),
 							self.gas_price_percentile,
+							self.max_log_range_blocks,
 						).to_delegate(),
 					);
 					#[cfg(feature = "accounts")]
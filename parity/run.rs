@@ -29,7 +29,7 @@ use ethcore::spec::{SpecParams, OptimizeFor};
 use ethcore::verification::queue::VerifierSettings;
 use ethcore_logger::{Config as LogConfig, RotatingLogger};
 use ethcore_service::ClientService;
-use ethereum_types::Address;
+use ethereum_types::{Address, U256};
 use futures::IntoFuture;
 use hash_fetch::{self, fetch};
 use informant::{Informant, LightNodeInformantData, FullNodeInformantData};
@@ -95,6 +95,10 @@ pub struct RunCmd {
 	pub miner_options: MinerOptions,
 	pub gas_price_percentile: usize,
 	pub poll_lifetime: u32,
+	pub max_log_range_blocks: Option<u64>,
+	pub gas_cap: Option<U256>,
+	pub max_batch_size: Option<usize>,
+	pub pending_transactions_rate_limit: Option<usize>,
 	pub ws_conf: rpc::WsConfiguration,
 	pub http_conf: rpc::HttpConfiguration,
 	pub ipc_conf: rpc::IpcConfiguration,
@@ -340,13 +344,17 @@ fn execute_light_impl<Cr>(cmd: RunCmd, logger: Arc<RotatingLogger>, on_client_rq
 		whisper_rpc: whisper_factory,
 		private_tx_service: None, //TODO: add this to client.
 		gas_price_percentile: cmd.gas_price_percentile,
-		poll_lifetime: cmd.poll_lifetime
+		poll_lifetime: cmd.poll_lifetime,
+		max_log_range_blocks: cmd.max_log_range_blocks,
+		gas_cap: cmd.gas_cap,
+		pending_transactions_rate_limit: cmd.pending_transactions_rate_limit,
 	});
 
 	let dependencies = rpc::Dependencies {
 		apis: deps_for_rpc_apis.clone(),
 		executor: runtime.executor(),
 		stats: rpc_stats.clone(),
+		max_batch_size: cmd.max_batch_size,
 	};
 
 	// start rpc servers
@@ -753,12 +761,16 @@ fn execute_impl<Cr, Rr>(cmd: RunCmd, logger: Arc<RotatingLogger>, on_client_rq:
 		gas_price_percentile: cmd.gas_price_percentile,
 		poll_lifetime: cmd.poll_lifetime,
 		allow_missing_blocks: cmd.allow_missing_blocks,
+		max_log_range_blocks: cmd.max_log_range_blocks,
+		gas_cap: cmd.gas_cap,
+		pending_transactions_rate_limit: cmd.pending_transactions_rate_limit,
 	});
 
 	let dependencies = rpc::Dependencies {
 		apis: deps_for_rpc_apis.clone(),
 		executor: runtime.executor(),
 		stats: rpc_stats.clone(),
+		max_batch_size: cmd.max_batch_size,
 	};
 
 	// start rpc servers
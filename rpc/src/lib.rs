@@ -214,6 +214,8 @@ pub fn start_ipc<M, S, H, T>(
 	H: Into<jsonrpc_core::MetaIoHandler<M, S>>,
 	T: IpcMetaExtractor<M>,
 {
+	// TODO: reject oversized payloads before parsing like `start_http` does,
+	// jsonrpc-ipc-server 10 has no request size option.
 	ipc::ServerBuilder::with_meta_extractor(handler, extractor)
 		.start(addr)
 }
@@ -236,6 +238,8 @@ pub fn start_ws<M, S, H, T, U, V>(
 	U: ws::SessionStats,
 	V: ws::RequestMiddleware,
 {
+	// TODO: reject oversized payloads before parsing like `start_http` does,
+	// jsonrpc-ws-server 10 has no payload size option.
 	ws::ServerBuilder::with_meta_extractor(handler, extractor)
 		.request_middleware(middleware)
 		.allowed_origins(allowed_origins)
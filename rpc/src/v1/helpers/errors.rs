@@ -20,6 +20,7 @@ use std::fmt;
 
 use ethcore::error::{Error as EthcoreError, ErrorKind, CallError};
 use ethcore::client::BlockId;
+use ethereum_types::U256;
 use jsonrpc_core::{futures, Result as RpcResult, Error, ErrorCode, Value};
 use rlp::DecoderError;
 use types::transaction::Error as TransactionError;
@@ -38,6 +39,8 @@ mod codes {
 	pub const NO_NEW_WORK: i64 = -32003;
 	pub const NO_WORK_REQUIRED: i64 = -32004;
 	pub const CANNOT_SUBMIT_WORK: i64 = -32005;
+	// Same code as used by other providers (Infura, Alchemy) for oversized log queries.
+	pub const LOG_QUERY_RANGE_TOO_LARGE: i64 = -32005;
 	pub const UNKNOWN_ERROR: i64 = -32009;
 	pub const TRANSACTION_ERROR: i64 = -32010;
 	pub const EXECUTION_ERROR: i64 = -32015;
@@ -110,6 +113,14 @@ pub fn request_rejected_limit() -> Error {
 	}
 }
 
+pub fn batch_too_large(len: usize, max_batch_size: usize) -> Error {
+	Error {
+		code: ErrorCode::InvalidRequest,
+		message: format!("Batch of {} requests exceeds the limit of {} requests.", len, max_batch_size),
+		data: None,
+	}
+}
+
 pub fn request_rejected_param_limit(limit: u64, items_desc: &str) -> Error {
 	Error {
 		code: ErrorCode::ServerError(codes::REQUEST_REJECTED_LIMIT),
@@ -153,6 +164,14 @@ pub fn invalid_params<T: fmt::Debug>(param: &str, details: T) -> Error {
 	}
 }
 
+pub fn gas_cap_reached(gas_cap: U256) -> Error {
+	Error {
+		code: ErrorCode::ServerError(codes::EXECUTION_ERROR),
+		message: format!("Out of gas: gas was capped at the RPC gas cap of {}.", gas_cap),
+		data: None,
+	}
+}
+
 pub fn execution<T: fmt::Debug>(data: T) -> Error {
 	Error {
 		code: ErrorCode::ServerError(codes::EXECUTION_ERROR),
@@ -533,6 +552,14 @@ pub fn filter_block_not_found(id: BlockId) -> Error {
 	}
 }
 
+pub fn log_query_range_too_large(max_range: u64) -> Error {
+	Error {
+		code: ErrorCode::ServerError(codes::LOG_QUERY_RANGE_TOO_LARGE),
+		message: "Log query range too large".into(),
+		data: Some(Value::String(format!("Queries may span at most {} blocks, split the range into smaller chunks", max_range))),
+	}
+}
+
 pub fn on_demand_error(err: OnDemandError) -> Error {
 	match err {
 		OnDemandError(OnDemandErrorKind::ChannelCanceled(e), _) => on_demand_cancel(e),
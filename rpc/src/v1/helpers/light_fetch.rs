@@ -52,7 +52,7 @@ use std::collections::BTreeMap;
 use types::transaction::{Action, Transaction as EthTransaction, PendingTransaction, SignedTransaction, LocalizedTransaction};
 
 use v1::helpers::{CallRequest as CallRequestHelper, errors, dispatch};
-use v1::types::{BlockNumber, CallRequest, Log, Transaction};
+use v1::types::{BlockNumber, Log, Transaction};
 
 const NO_INVALID_BACK_REFS_PROOF: &str = "Fails only on invalid back-references; back-references here known to be valid; qed";
 const WRONG_RESPONSE_AMOUNT_TYPE_PROOF: &str = "responses correspond directly with requests in amount and type; qed";
@@ -246,7 +246,7 @@ where
 	/// Helper for getting proved execution.
 	pub fn proved_read_only_execution(
 		&self,
-		req: CallRequest,
+		req: CallRequestHelper,
 		num: Option<BlockNumber>,
 		txq: Arc<RwLock<TransactionQueue>>
 	) -> impl Future<Item = ExecutionResult, Error = Error> + Send {
@@ -255,7 +255,6 @@ where
 		const START_GAS: u64 = 60_000;
 
 		let (sync, on_demand, client) = (self.sync.clone(), self.on_demand.clone(), self.client.clone());
-		let req: CallRequestHelper = req.into();
 
 		// Note: Here we treat `Pending` as `Latest`.
 		//       Since light clients don't produce pending blocks
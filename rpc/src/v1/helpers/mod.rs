@@ -33,6 +33,7 @@ pub mod nonce;
 pub mod secretstore;
 
 mod network_settings;
+mod peers;
 mod poll_filter;
 mod poll_manager;
 mod requests;
@@ -44,8 +45,9 @@ mod signature;
 pub use self::dispatch::{Dispatcher, FullDispatcher, LightDispatcher};
 pub use self::signature::verify_signature;
 pub use self::network_settings::NetworkSettings;
+pub use self::peers::peers_by_protocol;
 pub use self::poll_manager::PollManager;
-pub use self::poll_filter::{PollFilter, SyncPollFilter, limit_logs};
+pub use self::poll_filter::{PollFilter, SyncPollFilter, check_log_range, limit_logs};
 pub use self::requests::{
 	TransactionRequest, FilledTransactionRequest, ConfirmationRequest, ConfirmationPayload, CallRequest,
 };
@@ -0,0 +1,36 @@
+// Copyright 2015-2019 Parity Technologies (UK) Ltd.
+// This file is part of Parity Ethereum.
+
+// Parity Ethereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Ethereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Ethereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Helpers summarising connected peers
+
+use std::collections::BTreeMap;
+
+use sync::PeerInfo;
+
+/// Counts peers by the negotiated subprotocol version, e.g. `eth/63`.
+///
+/// Versions without connected peers are not included.
+pub fn peers_by_protocol(peers: &[PeerInfo]) -> BTreeMap<String, usize> {
+	let mut counts = BTreeMap::new();
+	for peer in peers {
+		let eth = peer.eth_info.as_ref().map(|info| format!("eth/{}", info.version));
+		let pip = peer.pip_info.as_ref().map(|info| format!("pip/{}", info.version));
+		for protocol in eth.into_iter().chain(pip) {
+			*counts.entry(protocol).or_insert(0) += 1;
+		}
+	}
+	counts
+}
@@ -21,7 +21,9 @@ use std::{
 	sync::Arc,
 };
 use ethereum_types::H256;
+use jsonrpc_core::Result;
 use parking_lot::Mutex;
+use v1::helpers::errors;
 use v1::types::Log;
 use types::filter::Filter;
 
@@ -70,6 +72,17 @@ impl PollFilter {
 	pub (in v1) const MAX_BLOCK_HISTORY_SIZE: usize = 32;
 }
 
+/// Checks that a log query spanning blocks `from..=to` covers at most `max_range` blocks.
+///
+/// Blocks which cannot be resolved are not checked here, fetching the logs reports them.
+pub fn check_log_range(from: Option<BlockNumber>, to: Option<BlockNumber>, max_range: Option<u64>) -> Result<()> {
+	match (from, to, max_range) {
+		(Some(from), Some(to), Some(max_range)) if to.saturating_sub(from) >= max_range =>
+			Err(errors::log_query_range_too_large(max_range)),
+		_ => Ok(()),
+	}
+}
+
 /// Returns only last `n` logs
 pub fn limit_logs(mut logs: Vec<Log>, limit: Option<usize>) -> Vec<Log> {
 	let len = logs.len();
@@ -18,13 +18,15 @@
 
 use std::sync::Arc;
 
-use ethcore::client::BlockChainClient;
+use ethcore::client::{BlockChainClient, CallAnalytics, TransactionId};
+use ethereum_types::H256;
 use types::header::Header;
 use types::transaction::LocalizedTransaction;
 
 use jsonrpc_core::Result;
+use v1::helpers::errors;
 use v1::traits::Debug;
-use v1::types::{Block, Bytes, RichBlock, BlockTransactions, Transaction};
+use v1::types::{Block, Bytes, RichBlock, BlockTransactions, Transaction, CallFrame, DebugTraceOptions, CALL_TRACER};
 
 /// Debug rpc implementation.
 pub struct DebugClient<C> {
@@ -90,6 +92,27 @@ impl<C: BlockChainClient + 'static> Debug for DebugClient<C> {
 			}
 		}).collect())
 	}
+
+	fn trace_transaction(&self, transaction_hash: H256, options: Option<DebugTraceOptions>) -> Result<CallFrame> {
+		match options.and_then(|options| options.tracer) {
+			Some(ref tracer) if tracer == CALL_TRACER => {},
+			_ => return Err(errors::unsupported(
+				"Only the `callTracer` tracer is supported.",
+				Some("Pass `{\"tracer\": \"callTracer\"}` as the second parameter."),
+			)),
+		}
+
+		let analytics = CallAnalytics {
+			transaction_tracing: true,
+			vm_tracing: false,
+			state_diffing: false,
+		};
+
+		self.client.replay(TransactionId::Hash(transaction_hash), analytics)
+			.map_err(errors::call)
+			.and_then(|executed| CallFrame::from_executed(executed)
+				.ok_or_else(|| errors::internal("Transaction produced no trace.", "")))
+	}
 }
 
 fn serialize<T: ::serde::Serialize>(t: &T) -> String {
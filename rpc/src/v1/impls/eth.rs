@@ -26,6 +26,7 @@ use parking_lot::Mutex;
 
 use ethash::{self, SeedHashCompute};
 use ethcore::client::{BlockChainClient, BlockId, TransactionId, UncleId, StateOrBlock, StateClient, StateInfo, Call, EngineInfo, ProvingBlockChainClient};
+use ethcore::error::CallError;
 use ethcore::miner::{self, MinerService};
 use ethcore::snapshot::SnapshotService;
 use hash::keccak;
@@ -35,11 +36,12 @@ use types::transaction::{SignedTransaction, LocalizedTransaction};
 use types::BlockNumber as EthBlockNumber;
 use types::encoded;
 use types::filter::Filter as EthcoreFilter;
+use vm::Error as VMError;
 
 use jsonrpc_core::{BoxFuture, Result};
 use jsonrpc_core::futures::future;
 
-use v1::helpers::{self, errors, limit_logs, fake_sign};
+use v1::helpers::{self, errors, check_log_range, limit_logs, fake_sign};
 use v1::helpers::deprecated::{self, DeprecationNotice};
 use v1::helpers::dispatch::{FullDispatcher, default_gas_price};
 use v1::helpers::block_import::is_major_importing;
@@ -68,6 +70,12 @@ pub struct EthClientOptions {
 	pub allow_missing_blocks: bool,
 	/// Enable Experimental RPC-Calls
 	pub allow_experimental_rpcs: bool,
+	/// Maximum number of blocks a single `eth_getLogs` query may span.
+	/// `None` means the range is unlimited.
+	pub max_log_range_blocks: Option<u64>,
+	/// Maximum gas used by `eth_call` and `eth_estimateGas`, larger requests are clamped.
+	/// `None` means the gas is not capped.
+	pub gas_cap: Option<U256>,
 }
 
 impl EthClientOptions {
@@ -89,6 +97,8 @@ impl Default for EthClientOptions {
 			gas_price_percentile: 50,
 			allow_missing_blocks: false,
 			allow_experimental_rpcs: false,
+			max_log_range_blocks: Some(1000),
+			gas_cap: Some(50_000_000.into()),
 		}
 	}
 }
@@ -145,7 +155,7 @@ enum PendingTransactionId {
 	Location(PendingOrBlock, usize)
 }
 
-pub fn base_logs<C, M, T: StateInfo + 'static> (client: &C, miner: &M, filter: Filter) -> BoxFuture<Vec<Log>> where
+pub fn base_logs<C, M, T: StateInfo + 'static> (client: &C, miner: &M, filter: Filter, max_log_range_blocks: Option<u64>) -> BoxFuture<Vec<Log>> where
 	C: miner::BlockChainClient + BlockChainClient + StateClient<State=T> + Call<State=T>,
 	M: MinerService<State=T> {
 	let include_pending = filter.to_block == Some(BlockNumber::Pending);
@@ -153,6 +163,11 @@ pub fn base_logs<C, M, T: StateInfo + 'static> (client: &C, miner: &M, filter: F
 		Ok(value) => value,
 		Err(err) => return Box::new(future::err(err)),
 	};
+
+	let from = client.block_number(filter.from_block);
+	let to = client.block_number(filter.to_block);
+	try_bf!(check_log_range(from, to, max_log_range_blocks));
+
 	let mut logs = match client.logs(filter.clone()) {
 		Ok(logs) => logs
 			.into_iter()
@@ -172,6 +187,25 @@ pub fn base_logs<C, M, T: StateInfo + 'static> (client: &C, miner: &M, filter: F
 	Box::new(future::ok(logs))
 }
 
+/// Clamps the gas of an `eth_call`/`eth_estimateGas` request to the RPC gas cap.
+///
+/// Requests without gas, or asking for more than the cap, are given the cap.
+/// Returns the cap if it was applied, so running out of gas can be reported against it.
+pub fn apply_gas_cap(mut request: helpers::CallRequest, gas_cap: Option<U256>) -> (helpers::CallRequest, Option<U256>) {
+	let gas_cap = match gas_cap {
+		Some(gas_cap) => gas_cap,
+		None => return (request, None),
+	};
+
+	match request.gas {
+		Some(gas) if gas <= gas_cap => (request, None),
+		_ => {
+			request.gas = Some(gas_cap);
+			(request, Some(gas_cap))
+		},
+	}
+}
+
 impl<C, SN: ?Sized, S: ?Sized, M, EM, T: StateInfo + 'static> EthClient<C, SN, S, M, EM> where
 	C: miner::BlockChainClient + BlockChainClient + StateClient<State=T> + Call<State=T> + EngineInfo,
 	SN: SnapshotService,
@@ -817,7 +851,7 @@ impl<C, SN: ?Sized, S: ?Sized, M, EM, T: StateInfo + 'static> Eth for EthClient<
 	}
 
 	fn logs(&self, filter: Filter) -> BoxFuture<Vec<Log>> {
-		base_logs(&*self.client, &*self.miner, filter)
+		base_logs(&*self.client, &*self.miner, filter, self.options.max_log_range_blocks)
 	}
 
 	fn work(&self, no_new_work_timeout: Option<u64>) -> Result<Work> {
@@ -907,7 +941,7 @@ impl<C, SN: ?Sized, S: ?Sized, M, EM, T: StateInfo + 'static> Eth for EthClient<
 	}
 
 	fn call(&self, request: CallRequest, num: Option<BlockNumber>) -> BoxFuture<Bytes> {
-		let request = CallRequest::into(request);
+		let (request, capped_at) = apply_gas_cap(request.into(), self.options.gas_cap);
 		let signed = try_bf!(fake_sign::sign_call(request));
 
 		let num = num.unwrap_or_default();
@@ -937,9 +971,10 @@ impl<C, SN: ?Sized, S: ?Sized, M, EM, T: StateInfo + 'static> Eth for EthClient<
 		Box::new(future::done(result
 			.map_err(errors::call)
 			.and_then(|executed| {
-				match executed.exception {
-					Some(ref exception) => Err(errors::vm(exception, &executed.output)),
-					None => Ok(executed)
+				match (&executed.exception, capped_at) {
+					(&Some(VMError::OutOfGas), Some(gas_cap)) => Err(errors::gas_cap_reached(gas_cap)),
+					(&Some(ref exception), _) => Err(errors::vm(exception, &executed.output)),
+					(&None, _) => Ok(executed)
 				}
 			})
 			.map(|b| b.output.into())
@@ -947,7 +982,7 @@ impl<C, SN: ?Sized, S: ?Sized, M, EM, T: StateInfo + 'static> Eth for EthClient<
 	}
 
 	fn estimate_gas(&self, request: CallRequest, num: Option<BlockNumber>) -> BoxFuture<U256> {
-		let request = CallRequest::into(request);
+		let (request, capped_at) = apply_gas_cap(request.into(), self.options.gas_cap);
 		let signed = try_bf!(fake_sign::sign_call(request));
 		let num = num.unwrap_or_default();
 
@@ -976,10 +1011,17 @@ impl<C, SN: ?Sized, S: ?Sized, M, EM, T: StateInfo + 'static> Eth for EthClient<
 		};
 
 		Box::new(future::done(self.client.estimate_gas(&signed, &state, &header)
-			.map_err(errors::call)
+			.map_err(|err| match (err, capped_at) {
+				(CallError::Exceptional(VMError::OutOfGas), Some(gas_cap)) => errors::gas_cap_reached(gas_cap),
+				(err, _) => errors::call(err),
+			})
 		))
 	}
 
+	fn gas_cap_config(&self) -> Result<Option<U256>> {
+		Ok(self.options.gas_cap)
+	}
+
 	fn compile_lll(&self, _: String) -> Result<Bytes> {
 		Err(errors::deprecated("Compilation of LLL via RPC is deprecated".to_string()))
 	}
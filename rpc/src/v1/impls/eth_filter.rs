@@ -30,7 +30,7 @@ use jsonrpc_core::futures::{future, Future};
 use jsonrpc_core::futures::future::Either;
 use v1::traits::EthFilter;
 use v1::types::{BlockNumber, Index, Filter, FilterChanges, Log};
-use v1::helpers::{errors, SyncPollFilter, PollFilter, PollManager, limit_logs};
+use v1::helpers::{errors, SyncPollFilter, PollFilter, PollManager, check_log_range, limit_logs};
 use v1::impls::eth::pending_logs;
 
 /// Something which provides data that can be filtered over.
@@ -41,6 +41,12 @@ pub trait Filterable {
 	/// Get a block hash by block id.
 	fn block_hash(&self, id: BlockId) -> Option<H256>;
 
+	/// Get a block number by block id.
+	fn block_number(&self, id: BlockId) -> Option<u64>;
+
+	/// Maximum number of blocks a single log query may span, `None` if unlimited.
+	fn max_log_range_blocks(&self) -> Option<u64>;
+
 	/// pending transaction hashes at the given block (unordered).
 	fn pending_transaction_hashes(&self) -> BTreeSet<H256>;
 
@@ -62,15 +68,17 @@ pub struct EthFilterClient<C, M> {
 	client: Arc<C>,
 	miner: Arc<M>,
 	polls: Mutex<PollManager<SyncPollFilter>>,
+	max_log_range_blocks: Option<u64>,
 }
 
 impl<C, M> EthFilterClient<C, M> {
 	/// Creates new Eth filter client.
-	pub fn new(client: Arc<C>, miner: Arc<M>, poll_lifetime: u32) -> Self {
+	pub fn new(client: Arc<C>, miner: Arc<M>, poll_lifetime: u32, max_log_range_blocks: Option<u64>) -> Self {
 		EthFilterClient {
 			client,
 			miner,
 			polls: Mutex::new(PollManager::new(poll_lifetime)),
+			max_log_range_blocks,
 		}
 	}
 }
@@ -87,6 +95,14 @@ impl<C, M> Filterable for EthFilterClient<C, M> where
 		self.client.block_hash(id)
 	}
 
+	fn block_number(&self, id: BlockId) -> Option<u64> {
+		self.client.block_number(id)
+	}
+
+	fn max_log_range_blocks(&self) -> Option<u64> {
+		self.max_log_range_blocks
+	}
+
 	fn pending_transaction_hashes(&self) -> BTreeSet<H256> {
 		self.miner.pending_transaction_hashes(&*self.client)
 	}
@@ -289,6 +305,10 @@ impl<T: Filterable + Send + Sync + 'static> EthFilter for T {
 			}
 		};
 
+		let from = self.block_number(filter.from_block);
+		let to = self.block_number(filter.to_block);
+		try_bf!(check_log_range(from, to, self.max_log_range_blocks()));
+
 		// fetch pending logs.
 		let pending = if include_pending {
 			let best_block = self.best_block_number();
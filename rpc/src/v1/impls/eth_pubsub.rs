@@ -18,6 +18,7 @@
 
 use std::sync::{Arc, Weak};
 use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
 
 use jsonrpc_core::{BoxFuture, Result, Error};
 use jsonrpc_core::futures::{self, Future, IntoFuture};
@@ -27,7 +28,7 @@ use v1::helpers::{errors, limit_logs, Subscribers};
 use v1::helpers::light_fetch::LightFetch;
 use v1::metadata::Metadata;
 use v1::traits::EthPubSub;
-use v1::types::{pubsub, RichHeader, Log};
+use v1::types::{pubsub, RichHeader, Log, Transaction};
 
 use ethcore::client::{BlockChainClient, ChainNotify, NewBlocks, ChainRouteType, BlockId};
 use ethereum_types::H256;
@@ -41,15 +42,62 @@ use sync::{LightSyncProvider, LightNetworkDispatcher, ManageNetwork};
 
 use types::encoded;
 use types::filter::Filter as EthFilter;
+use types::transaction::PendingTransaction;
 
 type Client = Sink<pubsub::Result>;
 
+/// Default number of full pending transactions pushed to a single subscriber per second.
+pub const DEFAULT_PENDING_TRANSACTIONS_RATE_LIMIT: usize = 100;
+
+/// Caps the number of notifications sent to a single subscriber per second.
+///
+/// The count is reset at the start of each fixed one second window. Notifications
+/// over the limit are dropped rather than queued, so a busy transaction pool can't
+/// flood a slow subscriber.
+struct RateLimit {
+	max_per_second: Option<usize>,
+	window: Mutex<(Instant, usize)>,
+}
+
+impl RateLimit {
+	/// Creates a new limiter, `None` disables limiting.
+	fn new(max_per_second: Option<usize>) -> Self {
+		RateLimit {
+			max_per_second,
+			window: Mutex::new((Instant::now(), 0)),
+		}
+	}
+
+	/// Returns `true` if another notification may be sent now.
+	fn allow(&self) -> bool {
+		let max = match self.max_per_second {
+			Some(max) => max,
+			None => return true,
+		};
+
+		let mut window = self.window.lock();
+		let now = Instant::now();
+		if now.duration_since(window.0) >= Duration::from_secs(1) {
+			*window = (now, 0);
+		}
+
+		if window.1 < max {
+			window.1 += 1;
+			true
+		} else {
+			false
+		}
+	}
+}
+
 /// Eth PubSub implementation.
 pub struct EthPubSubClient<C> {
 	handler: Arc<ChainNotificationHandler<C>>,
 	heads_subscribers: Arc<RwLock<Subscribers<Client>>>,
 	logs_subscribers: Arc<RwLock<Subscribers<(Client, EthFilter)>>>,
 	transactions_subscribers: Arc<RwLock<Subscribers<Client>>>,
+	full_transactions_subscribers: Arc<RwLock<Subscribers<(Client, RateLimit)>>>,
+	pending_transactions_rate_limit: Option<usize>,
 }
 
 impl<C> EthPubSubClient<C> {
@@ -58,6 +106,7 @@ impl<C> EthPubSubClient<C> {
 		let heads_subscribers = Arc::new(RwLock::new(Subscribers::default()));
 		let logs_subscribers = Arc::new(RwLock::new(Subscribers::default()));
 		let transactions_subscribers = Arc::new(RwLock::new(Subscribers::default()));
+		let full_transactions_subscribers = Arc::new(RwLock::new(Subscribers::default()));
 
 		EthPubSubClient {
 			handler: Arc::new(ChainNotificationHandler {
@@ -66,13 +115,23 @@ impl<C> EthPubSubClient<C> {
 				heads_subscribers: heads_subscribers.clone(),
 				logs_subscribers: logs_subscribers.clone(),
 				transactions_subscribers: transactions_subscribers.clone(),
+				full_transactions_subscribers: full_transactions_subscribers.clone(),
 			}),
 			heads_subscribers,
 			logs_subscribers,
 			transactions_subscribers,
+			full_transactions_subscribers,
+			pending_transactions_rate_limit: Some(DEFAULT_PENDING_TRANSACTIONS_RATE_LIMIT),
 		}
 	}
 
+	/// Sets the maximal number of full pending transactions pushed to a single subscriber per second.
+	/// `None` disables the limit.
+	pub fn with_pending_transactions_rate_limit(mut self, limit: Option<usize>) -> Self {
+		self.pending_transactions_rate_limit = limit;
+		self
+	}
+
 	/// Creates new `EthPubSubCient` with deterministic subscription ids.
 	#[cfg(test)]
 	pub fn new_test(client: Arc<C>, executor: Executor) -> Self {
@@ -80,6 +139,7 @@ impl<C> EthPubSubClient<C> {
 		*client.heads_subscribers.write() = Subscribers::new_test();
 		*client.logs_subscribers.write() = Subscribers::new_test();
 		*client.transactions_subscribers.write() = Subscribers::new_test();
+		*client.full_transactions_subscribers.write() = Subscribers::new_test();
 		client
 	}
 
@@ -121,6 +181,7 @@ pub struct ChainNotificationHandler<C> {
 	heads_subscribers: Arc<RwLock<Subscribers<Client>>>,
 	logs_subscribers: Arc<RwLock<Subscribers<(Client, EthFilter)>>>,
 	transactions_subscribers: Arc<RwLock<Subscribers<Client>>>,
+	full_transactions_subscribers: Arc<RwLock<Subscribers<(Client, RateLimit)>>>,
 }
 
 impl<C> ChainNotificationHandler<C> {
@@ -184,6 +245,31 @@ impl<C> ChainNotificationHandler<C> {
 			}
 		}
 	}
+
+	/// Notify all subscribers about new transactions.
+	pub fn notify_new_full_transactions<'a, I>(&self, transactions: I) where
+		I: IntoIterator<Item = &'a PendingTransaction>,
+	{
+		let subscribers = self.full_transactions_subscribers.read();
+		if subscribers.is_empty() {
+			return;
+		}
+
+		let transactions = transactions
+			.into_iter()
+			.map(|tx| Transaction::from_pending(tx.clone()))
+			.collect::<Vec<_>>();
+
+		for &(ref subscriber, ref rate_limit) in subscribers.values() {
+			for transaction in &transactions {
+				if !rate_limit.allow() {
+					trace!(target: "pubsub", "Rate limit reached, dropping pending transaction {:?}", transaction.hash);
+					continue;
+				}
+				Self::notify(&self.executor, subscriber, pubsub::Result::FullTransaction(Box::new(transaction.clone())));
+			}
+		}
+	}
 }
 
 /// A light client wrapper struct.
@@ -296,6 +382,14 @@ impl<C: Send + Sync + 'static> EthPubSub for EthPubSubClient<C> {
 			(pubsub::Kind::NewPendingTransactions, _) => {
 				errors::invalid_params("newPendingTransactions", "Expected no parameters.")
 			},
+			(pubsub::Kind::PendingTransactions, None) => {
+				let rate_limit = RateLimit::new(self.pending_transactions_rate_limit);
+				self.full_transactions_subscribers.write().push(subscriber, rate_limit);
+				return;
+			},
+			(pubsub::Kind::PendingTransactions, _) => {
+				errors::invalid_params("pendingTransactions", "Expected no parameters.")
+			},
 			_ => {
 				errors::unimplemented(None)
 			},
@@ -308,7 +402,8 @@ impl<C: Send + Sync + 'static> EthPubSub for EthPubSubClient<C> {
 		let res = self.heads_subscribers.write().remove(&id).is_some();
 		let res2 = self.logs_subscribers.write().remove(&id).is_some();
 		let res3 = self.transactions_subscribers.write().remove(&id).is_some();
+		let res4 = self.full_transactions_subscribers.write().remove(&id).is_some();
 
-		Ok(res || res2 || res3)
+		Ok(res || res2 || res3 || res4)
 	}
 }
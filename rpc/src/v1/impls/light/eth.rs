@@ -36,9 +36,11 @@ use types::transaction::SignedTransaction;
 use types::encoded;
 use types::filter::Filter as EthcoreFilter;
 use types::ids::BlockId;
+use vm::Error as VMError;
 
+use v1::impls::eth::apply_gas_cap;
 use v1::impls::eth_filter::Filterable;
-use v1::helpers::{errors, limit_logs, SyncPollFilter, PollManager};
+use v1::helpers::{errors, check_log_range, limit_logs, SyncPollFilter, PollManager};
 use v1::helpers::deprecated::{self, DeprecationNotice};
 use v1::helpers::light_fetch::{self, LightFetch};
 use v1::traits::Eth;
@@ -63,6 +65,8 @@ pub struct EthClient<C, S: LightSyncProvider + LightNetworkDispatcher + 'static,
 	polls: Mutex<PollManager<SyncPollFilter>>,
 	poll_lifetime: u32,
 	gas_price_percentile: usize,
+	max_log_range_blocks: Option<u64>,
+	gas_cap: Option<U256>,
 	deprecation_notice: DeprecationNotice,
 }
 
@@ -83,6 +87,8 @@ where
 			polls: Mutex::new(PollManager::new(self.poll_lifetime)),
 			poll_lifetime: self.poll_lifetime,
 			gas_price_percentile: self.gas_price_percentile,
+			max_log_range_blocks: self.max_log_range_blocks,
+			gas_cap: self.gas_cap,
 			deprecation_notice: Default::default(),
 		}
 	}
@@ -104,7 +110,9 @@ where
 		accounts: Arc<Fn() -> Vec<Address> + Send + Sync>,
 		cache: Arc<Mutex<LightDataCache>>,
 		gas_price_percentile: usize,
-		poll_lifetime: u32
+		poll_lifetime: u32,
+		max_log_range_blocks: Option<u64>,
+		gas_cap: Option<U256>,
 	) -> Self {
 		EthClient {
 			sync,
@@ -116,6 +124,8 @@ where
 			polls: Mutex::new(PollManager::new(poll_lifetime)),
 			poll_lifetime,
 			gas_price_percentile,
+			max_log_range_blocks,
+			gas_cap,
 			deprecation_notice: Default::default(),
 		}
 	}
@@ -401,24 +411,36 @@ where
 	}
 
 	fn call(&self, req: CallRequest, num: Option<BlockNumber>) -> BoxFuture<Bytes> {
-		Box::new(self.fetcher().proved_read_only_execution(req, num, self.transaction_queue.clone()).and_then(|res| {
+		let (req, capped_at) = apply_gas_cap(req.into(), self.gas_cap);
+		Box::new(self.fetcher().proved_read_only_execution(req, num, self.transaction_queue.clone()).and_then(move |res| {
 			match res {
-				Ok(exec) => Ok(exec.output.into()),
+				Ok(exec) => match (exec.exception, capped_at) {
+					(Some(VMError::OutOfGas), Some(gas_cap)) => Err(errors::gas_cap_reached(gas_cap)),
+					_ => Ok(exec.output.into()),
+				},
 				Err(e) => Err(errors::execution(e)),
 			}
 		}))
 	}
 
 	fn estimate_gas(&self, req: CallRequest, num: Option<BlockNumber>) -> BoxFuture<U256> {
+		let (req, capped_at) = apply_gas_cap(req.into(), self.gas_cap);
 		// TODO: binary chop for more accurate estimates.
-		Box::new(self.fetcher().proved_read_only_execution(req, num, self.transaction_queue.clone()).and_then(|res| {
+		Box::new(self.fetcher().proved_read_only_execution(req, num, self.transaction_queue.clone()).and_then(move |res| {
 			match res {
-				Ok(exec) => Ok(exec.refunded + exec.gas_used),
+				Ok(exec) => match (exec.exception, capped_at) {
+					(Some(VMError::OutOfGas), Some(gas_cap)) => Err(errors::gas_cap_reached(gas_cap)),
+					_ => Ok(exec.refunded + exec.gas_used),
+				},
 				Err(e) => Err(errors::execution(e)),
 			}
 		}))
 	}
 
+	fn gas_cap_config(&self) -> Result<Option<U256>> {
+		Ok(self.gas_cap)
+	}
+
 	fn transaction_by_hash(&self, hash: H256) -> BoxFuture<Option<Transaction>> {
 		{
 			let tx_queue = self.transaction_queue.read();
@@ -510,12 +532,13 @@ where
 
 	fn logs(&self, filter: Filter) -> BoxFuture<Vec<Log>> {
 		let limit = filter.limit;
+		let filter: EthcoreFilter = try_bf!(filter.try_into());
+
+		let from = Filterable::block_number(self, filter.from_block);
+		let to = Filterable::block_number(self, filter.to_block);
+		try_bf!(check_log_range(from, to, self.max_log_range_blocks));
 
-		Box::new(
-			Filterable::logs(self, match filter.try_into() {
-				Ok(value) => value,
-				Err(err) => return Box::new(future::err(err)),
-			}).map(move |logs| limit_logs(logs, limit)))
+		Box::new(Filterable::logs(self, filter).map(move |logs| limit_logs(logs, limit)))
 	}
 
 	fn work(&self, _timeout: Option<u64>) -> Result<Work> {
@@ -544,6 +567,14 @@ where
 		self.client.block_hash(id)
 	}
 
+	fn block_number(&self, id: BlockId) -> Option<u64> {
+		self.client.block_header(id).map(|header| header.number())
+	}
+
+	fn max_log_range_blocks(&self) -> Option<u64> {
+		self.max_log_range_blocks
+	}
+
 	fn pending_transaction_hashes(&self) -> BTreeSet<H256> {
 		BTreeSet::new()
 	}
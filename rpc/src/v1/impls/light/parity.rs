@@ -31,7 +31,9 @@ use ethcore_logger::RotatingLogger;
 use jsonrpc_core::{Result, BoxFuture};
 use jsonrpc_core::futures::{future, Future};
 use light::on_demand::OnDemandRequester;
-use v1::helpers::{self, errors, ipfs, NetworkSettings, verify_signature};
+use miner::pool;
+use types::filter::Filter as EthcoreFilter;
+use v1::helpers::{self, errors, ipfs, check_log_range, NetworkSettings, verify_signature};
 use v1::helpers::external_signer::{SignerService, SigningQueue};
 use v1::helpers::dispatch::LightDispatcher;
 use v1::helpers::light_fetch::{LightFetch, light_all_transactions};
@@ -44,7 +46,7 @@ use v1::types::{
 	LightBlockNumber, ChainStatus, Receipt,
 	BlockNumber, ConsensusCapability, VersionInfo,
 	OperationsInfo, Header, RichHeader, RecoveredAccount,
-	Log, Filter,
+	Log, Filter, TransactionFilter,
 };
 use Host;
 
@@ -60,6 +62,7 @@ where
 	signer: Option<Arc<SignerService>>,
 	ws_address: Option<Host>,
 	gas_price_percentile: usize,
+	max_log_range_blocks: Option<u64>,
 }
 
 impl<S, OD> ParityClient<S, OD>
@@ -75,6 +78,7 @@ where
 		signer: Option<Arc<SignerService>>,
 		ws_address: Option<Host>,
 		gas_price_percentile: usize,
+		max_log_range_blocks: Option<u64>,
 	) -> Self {
 		ParityClient {
 			light_dispatch,
@@ -83,6 +87,7 @@ where
 			signer,
 			ws_address,
 			gas_price_percentile,
+			max_log_range_blocks,
 		}
 	}
 
@@ -151,6 +156,10 @@ where
 		})
 	}
 
+	fn peers_by_protocol(&self) -> Result<BTreeMap<String, usize>> {
+		Ok(helpers::peers_by_protocol(&self.light_dispatch.sync.peers()))
+	}
+
 	fn net_port(&self) -> Result<u16> {
 		Ok(self.settings.network_port)
 	}
@@ -215,16 +224,18 @@ where
 			.map(Into::into)
 	}
 
-	fn pending_transactions(&self, limit: Option<usize>) -> Result<Vec<Transaction>> {
+	fn pending_transactions(&self, limit: Option<usize>, filter: Option<TransactionFilter>) -> Result<Vec<Transaction>> {
 		let txq = self.light_dispatch.transaction_queue.read();
 		let chain_info = self.light_dispatch.client.chain_info();
-		Ok(
-			txq.ready_transactions(chain_info.best_block_number, chain_info.best_block_timestamp)
-				.into_iter()
-				.take(limit.unwrap_or_else(usize::max_value))
-				.map(Transaction::from_pending)
-				.collect::<Vec<_>>()
-		)
+		let filter: Option<pool::TransactionFilter> = filter.map(Into::into);
+		let transactions = txq.ready_transactions_filtered(
+			chain_info.best_block_number,
+			chain_info.best_block_timestamp,
+			limit.unwrap_or_else(usize::max_value),
+			|tx| filter.as_ref().map_or(true, |filter| filter.matches(&tx.transaction)),
+		);
+
+		Ok(transactions.into_iter().map(Transaction::from_pending).collect())
 	}
 
 	fn all_transactions(&self) -> Result<Vec<Transaction>> {
@@ -397,10 +408,18 @@ where
 	}
 
 	fn logs_no_tx_hash(&self, filter: Filter) -> BoxFuture<Vec<Log>> {
-		let filter = match filter.try_into() {
+		let filter: EthcoreFilter = match filter.try_into() {
 			Ok(value) => value,
 			Err(err) => return Box::new(future::err(err)),
 		};
+
+		let client = &self.light_dispatch.client;
+		let from = client.block_header(filter.from_block).map(|header| header.number());
+		let to = client.block_header(filter.to_block).map(|header| header.number());
+		if let Err(err) = check_log_range(from, to, self.max_log_range_blocks) {
+			return Box::new(future::err(err));
+		}
+
 		Box::new(self.fetcher().logs_no_tx_hash(filter)) as BoxFuture<_>
 	}
 
@@ -43,7 +43,7 @@ pub mod light;
 pub use self::debug::DebugClient;
 pub use self::eth::{EthClient, EthClientOptions};
 pub use self::eth_filter::EthFilterClient;
-pub use self::eth_pubsub::EthPubSubClient;
+pub use self::eth_pubsub::{EthPubSubClient, DEFAULT_PENDING_TRANSACTIONS_RATE_LIMIT};
 pub use self::net::NetClient;
 pub use self::parity::ParityClient;
 #[cfg(any(test, feature = "accounts"))]
@@ -46,7 +46,7 @@ use v1::types::{
 	TransactionStats, LocalTransactionStatus,
 	BlockNumber, ConsensusCapability, VersionInfo,
 	OperationsInfo, ChainStatus, Log, Filter,
-	RichHeader, Receipt, RecoveredAccount,
+	RichHeader, Receipt, RecoveredAccount, TransactionFilter,
 	block_number_to_id
 };
 use Host;
@@ -63,6 +63,7 @@ pub struct ParityClient<C, M, U> {
 	signer: Option<Arc<SignerService>>,
 	ws_address: Option<Host>,
 	snapshot: Option<Arc<SnapshotService>>,
+	max_log_range_blocks: Option<u64>,
 }
 
 impl<C, M, U> ParityClient<C, M, U> where
@@ -80,6 +81,7 @@ impl<C, M, U> ParityClient<C, M, U> where
 		signer: Option<Arc<SignerService>>,
 		ws_address: Option<Host>,
 		snapshot: Option<Arc<SnapshotService>>,
+		max_log_range_blocks: Option<u64>,
 	) -> Self {
 		ParityClient {
 			client,
@@ -92,6 +94,7 @@ impl<C, M, U> ParityClient<C, M, U> where
 			signer,
 			ws_address,
 			snapshot,
+			max_log_range_blocks,
 		}
 	}
 }
@@ -156,6 +159,10 @@ impl<C, M, U, S> Parity for ParityClient<C, M, U> where
 		})
 	}
 
+	fn peers_by_protocol(&self) -> Result<BTreeMap<String, usize>> {
+		Ok(helpers::peers_by_protocol(&self.sync.peers()))
+	}
+
 	fn net_port(&self) -> Result<u16> {
 		Ok(self.settings.network_port)
 	}
@@ -245,10 +252,11 @@ impl<C, M, U, S> Parity for ParityClient<C, M, U> where
 			.map(Into::into)
 	}
 
-	fn pending_transactions(&self, limit: Option<usize>) -> Result<Vec<Transaction>> {
-		let ready_transactions = self.miner.ready_transactions(
+	fn pending_transactions(&self, limit: Option<usize>, filter: Option<TransactionFilter>) -> Result<Vec<Transaction>> {
+		let ready_transactions = self.miner.ready_transactions_filtered(
 			&*self.client,
 			limit.unwrap_or_else(usize::max_value),
+			filter.map(Into::into),
 			miner::PendingOrdering::Priority,
 		);
 
@@ -458,7 +466,7 @@ impl<C, M, U, S> Parity for ParityClient<C, M, U> where
 	fn logs_no_tx_hash(&self, filter: Filter) -> BoxFuture<Vec<Log>> {
 		use v1::impls::eth::base_logs;
 		// only specific impl for lightclient
-		base_logs(&*self.client, &*self.miner, filter)
+		base_logs(&*self.client, &*self.miner, filter, self.max_log_range_blocks)
 	}
 
 	fn verify_signature(&self, is_prefixed: bool, message: Bytes, r: H256, s: H256, v: U64) -> Result<RecoveredAccount> {
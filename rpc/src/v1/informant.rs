@@ -25,6 +25,7 @@ use jsonrpc_core as core;
 use jsonrpc_core::futures::future::Either;
 use order_stat;
 use parking_lot::RwLock;
+use v1::helpers::errors;
 
 pub use self::parity_runtime::Executor;
 
@@ -184,9 +185,12 @@ pub trait ActivityNotifier: Send + Sync + 'static {
 }
 
 /// Stats-counting RPC middleware
+///
+/// Also rejects batches larger than `max_batch_size` without processing any of their calls.
 pub struct Middleware<T: ActivityNotifier = ClientNotifier> {
 	stats: Arc<RpcStats>,
 	notifier: T,
+	max_batch_size: Option<usize>,
 }
 
 impl<T: ActivityNotifier> Middleware<T> {
@@ -195,8 +199,15 @@ impl<T: ActivityNotifier> Middleware<T> {
 		Middleware {
 			stats,
 			notifier,
+			max_batch_size: None,
 		}
 	}
+
+	/// Limit the number of calls in a single batch request, `None` means no limit.
+	pub fn with_max_batch_size(mut self, max_batch_size: Option<usize>) -> Self {
+		self.max_batch_size = max_batch_size;
+		self
+	}
 }
 
 impl<M: core::Metadata, T: ActivityNotifier> core::Middleware<M> for Middleware<T> {
@@ -212,6 +223,17 @@ impl<M: core::Metadata, T: ActivityNotifier> core::Middleware<M> for Middleware<
 		self.notifier.active();
 		self.stats.count_request();
 
+		if let (core::Request::Batch(ref calls), Some(max)) = (&request, self.max_batch_size) {
+			if calls.len() > max {
+				let failure = core::Output::Failure(core::Failure {
+					jsonrpc: Some(core::Version::V2),
+					error: errors::batch_too_large(calls.len(), max),
+					id: core::Id::Null,
+				});
+				return Either::A(Box::new(core::futures::future::ok(Some(core::Response::Single(failure)))));
+			}
+		}
+
 		let id = match request {
 			core::Request::Single(core::Call::MethodCall(ref call)) => Some(call.id.clone()),
 			_ => None,
@@ -245,8 +267,59 @@ impl ActivityNotifier for ClientNotifier {
 
 #[cfg(test)]
 mod tests {
+	use std::sync::Arc;
+	use jsonrpc_core::{MetaIoHandler, Value};
+
+	use super::{ActivityNotifier, Middleware, RateCalculator, StatsCalculator, RpcStats};
+
+	struct NoopNotifier;
+
+	impl ActivityNotifier for NoopNotifier {
+		fn active(&self) {}
+	}
+
+	fn io(max_batch_size: Option<usize>) -> MetaIoHandler<(), Middleware<NoopNotifier>> {
+		let middleware = Middleware::new(Arc::new(RpcStats::default()), NoopNotifier)
+			.with_max_batch_size(max_batch_size);
+		let mut io = MetaIoHandler::with_middleware(middleware);
+		io.add_method("ping", |_| Ok(Value::String("pong".into())));
+		io
+	}
+
+	#[test]
+	fn should_reject_batch_over_limit() {
+		// given
+		let io = io(Some(2));
+		let request = r#"[
+			{"jsonrpc": "2.0", "method": "ping", "params": [], "id": 1},
+			{"jsonrpc": "2.0", "method": "ping", "params": [], "id": 2},
+			{"jsonrpc": "2.0", "method": "ping", "params": [], "id": 3}
+		]"#;
+
+		// when
+		let response = io.handle_request_sync(request, ());
 
-	use super::{RateCalculator, StatsCalculator, RpcStats};
+		// then
+		let expected = r#"{"jsonrpc":"2.0","error":{"code":-32600,"message":"Batch of 3 requests exceeds the limit of 2 requests."},"id":null}"#;
+		assert_eq!(response, Some(expected.to_owned()));
+	}
+
+	#[test]
+	fn should_process_batch_within_limit() {
+		// given
+		let io = io(Some(2));
+		let request = r#"[
+			{"jsonrpc": "2.0", "method": "ping", "params": [], "id": 1},
+			{"jsonrpc": "2.0", "method": "ping", "params": [], "id": 2}
+		]"#;
+
+		// when
+		let response = io.handle_request_sync(request, ());
+
+		// then
+		let expected = r#"[{"jsonrpc":"2.0","result":"pong","id":1},{"jsonrpc":"2.0","result":"pong","id":2}]"#;
+		assert_eq!(response, Some(expected.to_owned()));
+	}
 
 	#[test]
 	fn should_calculate_rate() {
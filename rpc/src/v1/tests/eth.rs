@@ -143,7 +143,9 @@ impl EthTester {
 				send_block_number_in_get_work: true,
 				gas_price_percentile: 50,
 				allow_experimental_rpcs: true,
-				allow_missing_blocks: false
+				allow_missing_blocks: false,
+				max_log_range_blocks: None,
+				gas_cap: Some(50_000_000.into()),
 			},
 		);
 
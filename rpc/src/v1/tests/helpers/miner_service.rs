@@ -27,7 +27,7 @@ use ethcore::error::Error;
 use ethcore::miner::{self, MinerService, AuthoringParams};
 use ethereum_types::{H256, U256, Address};
 use miner::pool::local_transactions::Status as LocalTransactionStatus;
-use miner::pool::{verifier, VerifiedTransaction, QueueStatus};
+use miner::pool::{verifier, VerifiedTransaction, QueueStatus, TransactionFilter};
 use parking_lot::{RwLock, Mutex};
 use types::transaction::{self, UnverifiedTransaction, SignedTransaction, PendingTransaction};
 use txpool;
@@ -218,8 +218,18 @@ impl MinerService for TestMinerService {
 		self.local_transactions.lock().iter().map(|(hash, stats)| (*hash, stats.clone())).collect()
 	}
 
-	fn ready_transactions<C>(&self, _chain: &C, _max_len: usize, _ordering: miner::PendingOrdering) -> Vec<Arc<VerifiedTransaction>> {
+	fn ready_transactions_filtered<C>(
+		&self,
+		_chain: &C,
+		max_len: usize,
+		filter: Option<TransactionFilter>,
+		_ordering: miner::PendingOrdering,
+	) -> Vec<Arc<VerifiedTransaction>> {
 		self.queued_transactions()
+			.into_iter()
+			.filter(|tx| filter.as_ref().map_or(true, |filter| filter.matches(tx.signed())))
+			.take(max_len)
+			.collect()
 	}
 
 	fn pending_transaction_hashes<C>(&self, _chain: &C) -> BTreeSet<H256> {
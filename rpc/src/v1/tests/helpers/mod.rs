@@ -16,11 +16,13 @@
 
 //! Test rpc services.
 
+mod executed;
 mod miner_service;
 mod snapshot_service;
 mod sync_provider;
 mod update_service;
 
+pub use self::executed::executed;
 pub use self::miner_service::TestMinerService;
 pub use self::snapshot_service::TestSnapshotService;
 pub use self::sync_provider::{Config, TestSyncProvider};
@@ -17,21 +17,69 @@
 use std::sync::Arc;
 
 use ethcore::client::TestBlockChainClient;
+use ethcore::executed::Executed;
+use ethcore::trace::{FlatTrace, trace};
 
 use jsonrpc_core::IoHandler;
 use v1::{Debug, DebugClient};
+use v1::tests::helpers::executed;
+use vm::CallType;
 
-fn io() -> IoHandler {
-	let client = Arc::new(TestBlockChainClient::new());
-
+fn io_with_client(client: Arc<TestBlockChainClient>) -> IoHandler {
 	let mut io = IoHandler::new();
 	io.extend_with(DebugClient::new(client).to_delegate());
 	io
 }
 
+fn io() -> IoHandler {
+	io_with_client(Arc::new(TestBlockChainClient::new()))
+}
+
+fn call_trace(from: u64, to: u64, subtraces: usize, trace_address: Vec<usize>) -> FlatTrace {
+	FlatTrace {
+		action: trace::Action::Call(trace::Call {
+			from: from.into(),
+			to: to.into(),
+			value: 0.into(),
+			gas: 0x100.into(),
+			input: vec![1, 2, 3],
+			call_type: CallType::Call,
+		}),
+		result: trace::Res::Call(trace::CallResult {
+			gas_used: 0x10.into(),
+			output: vec![4],
+		}),
+		subtraces,
+		trace_address,
+	}
+}
+
 #[test]
 fn rpc_debug_get_bad_blocks() {
 	let request = r#"{"jsonrpc": "2.0", "method": "debug_getBadBlocks", "params": [], "id": 1}"#;
 	let response = "{\"jsonrpc\":\"2.0\",\"result\":[{\"author\":\"0x0000000000000000000000000000000000000000\",\"difficulty\":\"0x0\",\"extraData\":\"0x\",\"gasLimit\":\"0x0\",\"gasUsed\":\"0x0\",\"hash\":\"0x27bfb37e507ce90da141307204b1c6ba24194380613590ac50ca4b1d7198ff65\",\"logsBloom\":\"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000\",\"miner\":\"0x0000000000000000000000000000000000000000\",\"number\":\"0x0\",\"parentHash\":\"0x0000000000000000000000000000000000000000000000000000000000000000\",\"reason\":\"Invalid block\",\"receiptsRoot\":\"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421\",\"rlp\":\"\\\"0x010203\\\"\",\"sealFields\":[],\"sha3Uncles\":\"0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347\",\"size\":\"0x3\",\"stateRoot\":\"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421\",\"timestamp\":\"0x0\",\"totalDifficulty\":null,\"transactions\":[],\"transactionsRoot\":\"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421\",\"uncles\":[]}],\"id\":1}";
 	assert_eq!(io().handle_request_sync(request), Some(response.to_owned()));
 }
+
+#[test]
+fn rpc_debug_trace_transaction_call_tracer() {
+	let client = Arc::new(TestBlockChainClient::new());
+	*client.execution_result.write() = Some(Ok(Executed {
+		gas: 20_000.into(),
+		gas_used: 10_000.into(),
+		output: vec![4],
+		trace: vec![call_trace(0xf, 0x10, 1, vec![]), call_trace(0x10, 0x11, 0, vec![0])],
+		..executed()
+	}));
+
+	let request = r#"{"jsonrpc": "2.0", "method": "debug_traceTransaction", "params": ["0x0000000000000000000000000000000000000000000000000000000000000005", {"tracer": "callTracer"}], "id": 1}"#;
+	let response = r#"{"jsonrpc":"2.0","result":{"calls":[{"from":"0x0000000000000000000000000000000000000010","gas":"0x100","gasUsed":"0x10","input":"0x010203","output":"0x04","to":"0x0000000000000000000000000000000000000011","type":"CALL","value":"0x0"}],"from":"0x000000000000000000000000000000000000000f","gas":"0x4e20","gasUsed":"0x2710","input":"0x010203","output":"0x04","to":"0x0000000000000000000000000000000000000010","type":"CALL","value":"0x0"},"id":1}"#;
+	assert_eq!(io_with_client(client).handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_debug_trace_transaction_requires_call_tracer() {
+	let request = r#"{"jsonrpc": "2.0", "method": "debug_traceTransaction", "params": ["0x0000000000000000000000000000000000000000000000000000000000000005"], "id": 1}"#;
+	let response = r#"{"jsonrpc":"2.0","error":{"code":-32000,"message":"Only the `callTracer` tracer is supported.","data":"Pass `{\"tracer\": \"callTracer\"}` as the second parameter."},"id":1}"#;
+	assert_eq!(io().handle_request_sync(request), Some(response.to_owned()));
+}
@@ -35,7 +35,7 @@ use types::receipt::{LocalizedReceipt, TransactionOutcome};
 
 use jsonrpc_core::IoHandler;
 use v1::{Eth, EthClient, EthClientOptions, EthFilter, EthFilterClient};
-use v1::tests::helpers::{TestSyncProvider, Config, TestMinerService, TestSnapshotService};
+use v1::tests::helpers::{executed, TestSyncProvider, Config, TestMinerService, TestSnapshotService};
 use v1::metadata::Metadata;
 
 fn blockchain_client() -> Arc<TestBlockChainClient> {
@@ -91,8 +91,9 @@ impl EthTester {
 		let snapshot = snapshot_service();
 		let hashrates = Arc::new(Mutex::new(HashMap::new()));
 		let external_miner = Arc::new(ExternalMiner::new(hashrates.clone()));
+		let max_log_range_blocks = options.max_log_range_blocks;
 		let eth = EthClient::new(&client, &snapshot, &sync, &opt_ap, &miner, &external_miner, options).to_delegate();
-		let filter = EthFilterClient::new(client.clone(), miner.clone(), 60).to_delegate();
+		let filter = EthFilterClient::new(client.clone(), miner.clone(), 60, max_log_range_blocks).to_delegate();
 
 		let mut io: IoHandler<Metadata> = IoHandler::default();
 		io.extend_with(eth);
@@ -247,6 +248,36 @@ fn rpc_eth_logs_error() {
 	assert_eq!(tester.io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_eth_logs_range_too_large() {
+	let tester = EthTester::default();
+	let request = r#"{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{"fromBlock":"0x0","toBlock":"0x2710"}], "id": 1}"#;
+	let response = r#"{"jsonrpc":"2.0","error":{"code":-32005,"message":"Log query range too large","data":"Queries may span at most 1000 blocks, split the range into smaller chunks"},"id":1}"#;
+	assert_eq!(tester.io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_eth_filter_logs_range_too_large() {
+	let tester = EthTester::default();
+	let request = r#"{"jsonrpc": "2.0", "method": "eth_newFilter", "params": [{"fromBlock":"0x0","toBlock":"0x2710"}], "id": 1}"#;
+	let response = r#"{"jsonrpc":"2.0","result":"0x0","id":1}"#;
+	assert_eq!(tester.io.handle_request_sync(request), Some(response.to_owned()));
+
+	let request = r#"{"jsonrpc": "2.0", "method": "eth_getFilterLogs", "params": ["0x0"], "id": 1}"#;
+	let response = r#"{"jsonrpc":"2.0","error":{"code":-32005,"message":"Log query range too large","data":"Queries may span at most 1000 blocks, split the range into smaller chunks"},"id":1}"#;
+	assert_eq!(tester.io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_eth_logs_range_unlimited() {
+	let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+		options.max_log_range_blocks = None;
+	}));
+	let request = r#"{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{"fromBlock":"0x0","toBlock":"0x2710"}], "id": 1}"#;
+	let response = r#"{"jsonrpc":"2.0","result":[],"id":1}"#;
+	assert_eq!(tester.io.handle_request_sync(request), Some(response.to_owned()));
+}
+
 #[test]
 fn rpc_logs_filter() {
 	let tester = EthTester::default();
@@ -620,19 +651,7 @@ fn rpc_eth_code() {
 #[test]
 fn rpc_eth_call_latest() {
 	let tester = EthTester::default();
-	tester.client.set_execution_result(Ok(Executed {
-		exception: None,
-		gas: U256::zero(),
-		gas_used: U256::from(0xff30),
-		refunded: U256::from(0x5),
-		cumulative_gas_used: U256::zero(),
-		logs: vec![],
-		contracts_created: vec![],
-		output: vec![0x12, 0x34, 0xff],
-		trace: vec![],
-		vm_trace: None,
-		state_diff: None,
-	}));
+	tester.client.set_execution_result(Ok(executed()));
 
 	let request = r#"{
 		"jsonrpc": "2.0",
@@ -656,19 +675,7 @@ fn rpc_eth_call_latest() {
 #[test]
 fn rpc_eth_call() {
 	let tester = EthTester::default();
-	tester.client.set_execution_result(Ok(Executed {
-		exception: None,
-		gas: U256::zero(),
-		gas_used: U256::from(0xff30),
-		refunded: U256::from(0x5),
-		cumulative_gas_used: U256::zero(),
-		logs: vec![],
-		contracts_created: vec![],
-		output: vec![0x12, 0x34, 0xff],
-		trace: vec![],
-		vm_trace: None,
-		state_diff: None,
-	}));
+	tester.client.set_execution_result(Ok(executed()));
 
 	let request = r#"{
 		"jsonrpc": "2.0",
@@ -689,22 +696,100 @@ fn rpc_eth_call() {
 	assert_eq!(tester.io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+const CALL_ABOVE_GAS_CAP: &str = r#"{
+	"jsonrpc": "2.0",
+	"method": "eth_call",
+	"params": [{
+		"from": "0xb60e8dd61c5d32be8058bb8eb970870f07233155",
+		"to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+		"gas": "0x5f5e100"
+	},
+	"latest"],
+	"id": 1
+}"#;
+
 #[test]
-fn rpc_eth_call_default_block() {
+fn rpc_eth_call_above_gas_cap() {
+	let tester = EthTester::default();
+	tester.client.set_execution_result(Ok(executed()));
+
+	let response = r#"{"jsonrpc":"2.0","result":"0x1234ff","id":1}"#;
+
+	assert_eq!(tester.io.handle_request_sync(CALL_ABOVE_GAS_CAP), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_eth_call_above_gas_cap_out_of_gas() {
 	let tester = EthTester::default();
-	tester.client.set_execution_result(Ok(Executed {
-		exception: None,
-		gas: U256::zero(),
-		gas_used: U256::from(0xff30),
-		refunded: U256::from(0x5),
-		cumulative_gas_used: U256::zero(),
-		logs: vec![],
-		contracts_created: vec![],
-		output: vec![0x12, 0x34, 0xff],
-		trace: vec![],
-		vm_trace: None,
-		state_diff: None,
+	tester.client.set_execution_result(Ok(Executed { exception: Some(::vm::Error::OutOfGas), ..executed() }));
+
+	let response = r#"{"jsonrpc":"2.0","error":{"code":-32015,"message":"Out of gas: gas was capped at the RPC gas cap of 50000000."},"id":1}"#;
+
+	assert_eq!(tester.io.handle_request_sync(CALL_ABOVE_GAS_CAP), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_eth_call_without_gas_cap_out_of_gas() {
+	let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+		options.gas_cap = None;
 	}));
+	tester.client.set_execution_result(Ok(Executed { exception: Some(::vm::Error::OutOfGas), ..executed() }));
+
+	let response = r#"{"jsonrpc":"2.0","error":{"code":-32015,"message":"VM execution error.","data":"Out of gas"},"id":1}"#;
+
+	assert_eq!(tester.io.handle_request_sync(CALL_ABOVE_GAS_CAP), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_eth_estimate_gas_above_gas_cap() {
+	let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "eth_estimateGas",
+		"params": [{
+			"from": "0xb60e8dd61c5d32be8058bb8eb970870f07233155",
+			"to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+			"gas": "0x5f5e100"
+		},
+		"latest"],
+		"id": 1
+	}"#;
+
+	// the gas is clamped, the estimate still fits below the cap
+	let tester = EthTester::default();
+	let response = r#"{"jsonrpc":"2.0","result":"0x5208","id":1}"#;
+	assert_eq!(tester.io.handle_request_sync(request), Some(response.to_owned()));
+
+	// the estimate needs more than the cap
+	let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+		options.gas_cap = Some(0x5000.into());
+	}));
+	let response = r#"{"jsonrpc":"2.0","error":{"code":-32015,"message":"Out of gas: gas was capped at the RPC gas cap of 20480."},"id":1}"#;
+	assert_eq!(tester.io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_eth_gas_cap_config() {
+	let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+		options.gas_cap = Some(0x1000.into());
+	}));
+
+	let request = r#"{"jsonrpc": "2.0", "method": "eth_gasCapConfig", "params": [], "id": 1}"#;
+	let response = r#"{"jsonrpc":"2.0","result":"0x1000","id":1}"#;
+
+	assert_eq!(tester.io.handle_request_sync(request), Some(response.to_owned()));
+
+	let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+		options.gas_cap = None;
+	}));
+	let response = r#"{"jsonrpc":"2.0","result":null,"id":1}"#;
+
+	assert_eq!(tester.io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_eth_call_default_block() {
+	let tester = EthTester::default();
+	tester.client.set_execution_result(Ok(executed()));
 
 	let request = r#"{
 		"jsonrpc": "2.0",
@@ -727,19 +812,7 @@ fn rpc_eth_call_default_block() {
 #[test]
 fn rpc_eth_estimate_gas() {
 	let tester = EthTester::default();
-	tester.client.set_execution_result(Ok(Executed {
-		exception: None,
-		gas: U256::zero(),
-		gas_used: U256::from(0xff30),
-		refunded: U256::from(0x5),
-		cumulative_gas_used: U256::zero(),
-		logs: vec![],
-		contracts_created: vec![],
-		output: vec![0x12, 0x34, 0xff],
-		trace: vec![],
-		vm_trace: None,
-		state_diff: None,
-	}));
+	tester.client.set_execution_result(Ok(executed()));
 
 	let request = r#"{
 		"jsonrpc": "2.0",
@@ -763,19 +836,7 @@ fn rpc_eth_estimate_gas() {
 #[test]
 fn rpc_eth_estimate_gas_default_block() {
 	let tester = EthTester::default();
-	tester.client.set_execution_result(Ok(Executed {
-		exception: None,
-		gas: U256::zero(),
-		gas_used: U256::from(0xff30),
-		refunded: U256::from(0x5),
-		cumulative_gas_used: U256::zero(),
-		logs: vec![],
-		contracts_created: vec![],
-		output: vec![0x12, 0x34, 0xff],
-		trace: vec![],
-		vm_trace: None,
-		state_diff: None,
-	}));
+	tester.client.set_execution_result(Ok(executed()));
 
 	let request = r#"{
 		"jsonrpc": "2.0",
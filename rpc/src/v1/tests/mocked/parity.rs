@@ -43,6 +43,7 @@ pub struct Dependencies {
 	pub settings: Arc<NetworkSettings>,
 	pub network: Arc<ManageNetwork>,
 	pub ws_address: Option<Host>,
+	pub max_log_range_blocks: Option<u64>,
 }
 
 impl Dependencies {
@@ -67,6 +68,7 @@ impl Dependencies {
 			}),
 			network: Arc::new(TestManageNetwork),
 			ws_address: Some("127.0.0.1:18546".into()),
+			max_log_range_blocks: None,
 		}
 	}
 
@@ -82,6 +84,7 @@ impl Dependencies {
 			signer,
 			self.ws_address.clone(),
 			None,
+			self.max_log_range_blocks,
 		)
 	}
 
@@ -256,6 +259,29 @@ fn rpc_parity_net_peers() {
 	assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_parity_peers_by_protocol() {
+	let deps = Dependencies::new();
+	let io = deps.default_client();
+
+	let request = r#"{"jsonrpc": "2.0", "method": "parity_peersByProtocol", "params":[], "id": 1}"#;
+	let response = r#"{"jsonrpc":"2.0","result":{"eth/62":1,"eth/64":1},"id":1}"#;
+
+	assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_logs_no_tx_hash_range_too_large() {
+	let mut deps = Dependencies::new();
+	deps.max_log_range_blocks = Some(1000);
+	let io = deps.default_client();
+
+	let request = r#"{"jsonrpc": "2.0", "method": "parity_getLogsNoTransactionHash", "params": [{"fromBlock":"0x0","toBlock":"0x2710"}], "id": 1}"#;
+	let response = r#"{"jsonrpc":"2.0","error":{"code":-32005,"message":"Log query range too large","data":"Queries may span at most 1000 blocks, split the range into smaller chunks"},"id":1}"#;
+
+	assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
 #[test]
 fn rpc_parity_net_port() {
 	let deps = Dependencies::new();
@@ -322,6 +348,39 @@ fn rpc_parity_pending_transactions() {
 	assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_parity_pending_transactions_with_filter() {
+	use types::transaction::{Action, Transaction};
+
+	let deps = Dependencies::new();
+	let io = deps.default_client();
+
+	let tx = |gas_price: u64, sender: u64| Transaction {
+		nonce: 1.into(),
+		gas_price: gas_price.into(),
+		gas: 0x76c0.into(),
+		action: Action::Call(5.into()),
+		value: 0x9184e72au64.into(),
+		data: vec![]
+	}.fake_sign(sender.into());
+	let matching = tx(0x9184e72a000, 2);
+	let other_sender = tx(0x9184e72a000, 3);
+	let other_gas_price = tx(0x1, 2);
+	for signed in vec![matching, other_sender, other_gas_price] {
+		deps.miner.pending_transactions.lock().insert(signed.hash(), signed);
+	}
+
+	let request = r#"{"jsonrpc": "2.0", "method": "parity_pendingTransactions", "params":[null, {"from":"0x0000000000000000000000000000000000000002","gasPrice":{"min":"0x2"}}], "id": 1}"#;
+	let response = r#"{"jsonrpc":"2.0","result":[{"blockHash":null,"blockNumber":null,"chainId":null,"condition":null,"creates":null,"from":"0x0000000000000000000000000000000000000002","gas":"0x76c0","gasPrice":"0x9184e72a000","hash":"0xa2e0da8a8064e0b9f93e95a53c2db6d01280efb8ac72a708d25487e67dd0f8fc","input":"0x","nonce":"0x1","publicKey":null,"r":"0x1","raw":"0xe9018609184e72a0008276c0940000000000000000000000000000000000000005849184e72a80800101","s":"0x1","standardV":"0x4","to":"0x0000000000000000000000000000000000000005","transactionIndex":null,"v":"0x0","value":"0x9184e72a"}],"id":1}"#;
+
+	assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+
+	let request = r#"{"jsonrpc": "2.0", "method": "parity_pendingTransactions", "params":[null, {"to":"0x0000000000000000000000000000000000000006"}], "id": 1}"#;
+	let response = r#"{"jsonrpc":"2.0","result":[],"id":1}"#;
+
+	assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
 #[test]
 fn rpc_parity_encrypt() {
 	let deps = Dependencies::new();
@@ -126,6 +126,10 @@ pub trait Eth {
 	#[rpc(name = "eth_estimateGas")]
 	fn estimate_gas(&self, CallRequest, Option<BlockNumber>) -> BoxFuture<U256>;
 
+	/// Returns the gas cap applied to `eth_call` and `eth_estimateGas`, `null` if gas is not capped.
+	#[rpc(name = "eth_gasCapConfig")]
+	fn gas_cap_config(&self) -> Result<Option<U256>>;
+
 	/// Get transaction by its hash.
 	#[rpc(name = "eth_getTransactionByHash")]
 	fn transaction_by_hash(&self, H256) -> BoxFuture<Option<Transaction>>;
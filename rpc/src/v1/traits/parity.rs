@@ -27,7 +27,7 @@ use v1::types::{
 	TransactionStats, LocalTransactionStatus,
 	BlockNumber, ConsensusCapability, VersionInfo,
 	OperationsInfo, ChainStatus, Log, Filter,
-	RichHeader, Receipt,
+	RichHeader, Receipt, TransactionFilter,
 };
 
 /// Parity-specific rpc interface.
@@ -72,6 +72,11 @@ pub trait Parity {
 	#[rpc(name = "parity_netPeers")]
 	fn net_peers(&self) -> Result<Peers>;
 
+	/// Returns number of connected peers for each negotiated subprotocol version.
+	/// Versions without connected peers are omitted rather than reported as `0`.
+	#[rpc(name = "parity_peersByProtocol")]
+	fn peers_by_protocol(&self) -> Result<BTreeMap<String, usize>>;
+
 	/// Returns network port
 	#[rpc(name = "parity_netPort")]
 	fn net_port(&self) -> Result<u16>;
@@ -124,8 +129,11 @@ pub trait Parity {
 	fn encrypt_message(&self, H512, Bytes) -> Result<Bytes>;
 
 	/// Returns all pending transactions from transaction queue.
+	/// Optional second parameter filters transactions by sender, recipient, gas price and value ranges;
+	/// the limit applies to the filtered set. Filtered requests re-compute the pending set instead of
+	/// using the cached one, so they are more expensive on a large pool.
 	#[rpc(name = "parity_pendingTransactions")]
-	fn pending_transactions(&self, Option<usize>) -> Result<Vec<Transaction>>;
+	fn pending_transactions(&self, Option<usize>, Option<TransactionFilter>) -> Result<Vec<Transaction>>;
 
 	/// Returns all transactions from transaction queue.
 	///
@@ -0,0 +1,374 @@
+// Copyright 2015-2019 Parity Technologies (UK) Ltd.
+// This file is part of Parity Ethereum.
+
+// Parity Ethereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Ethereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Ethereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Geth-compatible `callTracer` output for `debug_traceTransaction`.
+
+use ethcore::client::Executed;
+use ethcore::trace::{FlatTrace, TraceError, trace};
+use ethereum_types::{H160, U256};
+use vm;
+
+use v1::types::Bytes;
+
+/// Name of the only tracer supported by `debug_traceTransaction`.
+pub const CALL_TRACER: &str = "callTracer";
+
+/// Options accepted by `debug_traceTransaction`.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DebugTraceOptions {
+	/// Tracer used to format the result.
+	pub tracer: Option<String>,
+}
+
+/// Kind of a call frame.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CallFrameType {
+	/// CALL
+	Call,
+	/// CALLCODE
+	CallCode,
+	/// DELEGATECALL
+	DelegateCall,
+	/// STATICCALL
+	StaticCall,
+	/// CREATE
+	Create,
+	/// SELFDESTRUCT
+	SelfDestruct,
+}
+
+impl From<vm::CallType> for CallFrameType {
+	fn from(c: vm::CallType) -> Self {
+		match c {
+			vm::CallType::None | vm::CallType::Call => CallFrameType::Call,
+			vm::CallType::CallCode => CallFrameType::CallCode,
+			vm::CallType::DelegateCall => CallFrameType::DelegateCall,
+			vm::CallType::StaticCall => CallFrameType::StaticCall,
+		}
+	}
+}
+
+/// Single frame of the nested call tree, in the format of Geth's `callTracer`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallFrame {
+	/// Kind of the frame.
+	#[serde(rename = "type")]
+	pub call_type: CallFrameType,
+	/// Caller.
+	pub from: H160,
+	/// Callee, created contract or refund address.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub to: Option<H160>,
+	/// Transferred value.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub value: Option<U256>,
+	/// Gas provided to the frame.
+	pub gas: U256,
+	/// Gas used by the frame, unknown for nested frames which failed.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub gas_used: Option<U256>,
+	/// Call data or init code.
+	pub input: Bytes,
+	/// Returned data or deployed code.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output: Option<Bytes>,
+	/// Error, if the frame failed.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+	/// Nested frames.
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub calls: Vec<CallFrame>,
+}
+
+impl CallFrame {
+	/// Builds the call tree of a replayed transaction.
+	///
+	/// Returns `None` if the transaction was replayed without transaction tracing
+	/// or produced no top-level trace.
+	pub fn from_executed(executed: Executed) -> Option<Self> {
+		let mut traces = executed.trace.into_iter();
+		let mut root = Self::from_traces(&mut traces)?;
+		// Geth reports the transaction gas limit and the gas used by the whole transaction at the top level.
+		root.gas = executed.gas;
+		root.gas_used = Some(executed.gas_used);
+		// Revert data is only kept for the whole transaction, nested frames can't report it.
+		if let Some(vm::Error::Reverted) = executed.exception {
+			root.output = Some(executed.output.into());
+		}
+		Some(root)
+	}
+
+	/// Consumes one frame and, recursively, all of its subtraces.
+	///
+	/// Flat traces are produced in depth-first order, so the subtraces of a frame
+	/// immediately follow it.
+	fn from_traces<I: Iterator<Item = FlatTrace>>(traces: &mut I) -> Option<Self> {
+		let t = traces.next()?;
+		let mut frame = Self::from_action(t.action, t.result)?;
+		for _ in 0..t.subtraces {
+			if let Some(call) = Self::from_traces(traces) {
+				frame.calls.push(call);
+			}
+		}
+		Some(frame)
+	}
+
+	fn from_action(action: trace::Action, result: trace::Res) -> Option<Self> {
+		let mut frame = match action {
+			trace::Action::Call(call) => {
+				let call_type = CallFrameType::from(call.call_type);
+				CallFrame {
+					call_type,
+					from: call.from,
+					to: Some(call.to),
+					// Value is not transferred by these calls and Geth omits it.
+					value: match call_type {
+						CallFrameType::DelegateCall | CallFrameType::StaticCall => None,
+						_ => Some(call.value),
+					},
+					gas: call.gas,
+					gas_used: None,
+					input: call.input.into(),
+					output: None,
+					error: None,
+					calls: Vec::new(),
+				}
+			},
+			trace::Action::Create(create) => CallFrame {
+				call_type: CallFrameType::Create,
+				from: create.from,
+				to: None,
+				value: Some(create.value),
+				gas: create.gas,
+				gas_used: None,
+				input: create.init.into(),
+				output: None,
+				error: None,
+				calls: Vec::new(),
+			},
+			trace::Action::Suicide(suicide) => CallFrame {
+				call_type: CallFrameType::SelfDestruct,
+				from: suicide.address,
+				to: Some(suicide.refund_address),
+				value: Some(suicide.balance),
+				gas: U256::zero(),
+				gas_used: Some(U256::zero()),
+				input: Bytes::default(),
+				output: None,
+				error: None,
+				calls: Vec::new(),
+			},
+			// Block rewards are not part of a transaction.
+			trace::Action::Reward(_) => return None,
+		};
+
+		match result {
+			trace::Res::Call(res) => {
+				frame.gas_used = Some(res.gas_used);
+				frame.output = Some(res.output.into());
+			},
+			trace::Res::Create(res) => {
+				frame.gas_used = Some(res.gas_used);
+				frame.to = Some(res.address);
+				frame.output = Some(res.code.into());
+			},
+			// Gas used by failed frames is not traced, so it is left out rather than guessed.
+			trace::Res::FailedCall(err) | trace::Res::FailedCreate(err) => {
+				frame.error = Some(error_message(&err).into());
+			},
+			trace::Res::None => {},
+		}
+
+		Some(frame)
+	}
+}
+
+/// Error message Geth's `callTracer` reports for a failed frame.
+///
+/// Errors without a Geth counterpart keep a lowercase version of Parity's message.
+fn error_message(err: &TraceError) -> &'static str {
+	match *err {
+		TraceError::OutOfGas => "out of gas",
+		TraceError::BadJumpDestination => "invalid jump destination",
+		TraceError::BadInstruction => "invalid opcode",
+		TraceError::StackUnderflow => "stack underflow",
+		TraceError::OutOfStack => "stack limit reached",
+		TraceError::BuiltIn => "precompiled contract failed",
+		TraceError::Internal => "internal error",
+		TraceError::MutableCallInStaticContext => "write protection",
+		TraceError::Wasm => "wasm runtime error",
+		TraceError::OutOfBounds => "return data out of bounds",
+		TraceError::Reverted => "execution reverted",
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json;
+	use ethcore::client::Executed;
+	use ethcore::trace::{FlatTrace, TraceError, trace};
+	use vm::CallType;
+	use v1::tests::helpers;
+	use super::*;
+
+	fn call(from: u64, to: u64, call_type: CallType) -> trace::Action {
+		trace::Action::Call(trace::Call {
+			from: from.into(),
+			to: to.into(),
+			value: 1.into(),
+			gas: 0x1000.into(),
+			input: vec![0x12],
+			call_type,
+		})
+	}
+
+	fn executed(trace: Vec<FlatTrace>) -> Executed {
+		Executed {
+			gas: 0x5208.into(),
+			gas_used: 0x2000.into(),
+			trace,
+			..helpers::executed()
+		}
+	}
+
+	#[test]
+	fn should_build_nested_call_tree() {
+		let traces = vec![
+			FlatTrace {
+				action: call(1, 2, CallType::Call),
+				result: trace::Res::Call(trace::CallResult { gas_used: 0x500.into(), output: vec![0x34] }),
+				subtraces: 2,
+				trace_address: vec![],
+			},
+			FlatTrace {
+				action: call(2, 3, CallType::DelegateCall),
+				result: trace::Res::Call(trace::CallResult { gas_used: 0x100.into(), output: vec![] }),
+				subtraces: 1,
+				trace_address: vec![0],
+			},
+			FlatTrace {
+				action: call(2, 4, CallType::StaticCall),
+				result: trace::Res::FailedCall(TraceError::Reverted),
+				subtraces: 0,
+				trace_address: vec![0, 0],
+			},
+			FlatTrace {
+				action: trace::Action::Suicide(trace::Suicide {
+					address: 2.into(),
+					refund_address: 1.into(),
+					balance: 5.into(),
+				}),
+				result: trace::Res::None,
+				subtraces: 0,
+				trace_address: vec![1],
+			},
+		];
+
+		let frame = CallFrame::from_executed(executed(traces)).unwrap();
+		assert_eq!(frame.calls.len(), 2);
+		assert_eq!(frame.calls[0].calls.len(), 1);
+
+		let serialized = serde_json::to_string(&frame).unwrap();
+		assert_eq!(serialized, r#"{"type":"CALL","from":"0x0000000000000000000000000000000000000001","to":"0x0000000000000000000000000000000000000002","value":"0x1","gas":"0x5208","gasUsed":"0x2000","input":"0x12","output":"0x34","calls":[{"type":"DELEGATECALL","from":"0x0000000000000000000000000000000000000002","to":"0x0000000000000000000000000000000000000003","gas":"0x1000","gasUsed":"0x100","input":"0x12","output":"0x","calls":[{"type":"STATICCALL","from":"0x0000000000000000000000000000000000000002","to":"0x0000000000000000000000000000000000000004","gas":"0x1000","input":"0x12","error":"execution reverted"}]},{"type":"SELFDESTRUCT","from":"0x0000000000000000000000000000000000000002","to":"0x0000000000000000000000000000000000000001","value":"0x5","gas":"0x0","gasUsed":"0x0","input":"0x"}]}"#);
+	}
+
+	#[test]
+	fn should_report_created_contract_address() {
+		let traces = vec![FlatTrace {
+			action: trace::Action::Create(trace::Create {
+				from: 1.into(),
+				value: 0.into(),
+				gas: 0x1000.into(),
+				init: vec![0x60],
+			}),
+			result: trace::Res::Create(trace::CreateResult {
+				gas_used: 0x10.into(),
+				code: vec![0x61],
+				address: 9.into(),
+			}),
+			subtraces: 0,
+			trace_address: vec![],
+		}];
+
+		let frame = CallFrame::from_executed(executed(traces)).unwrap();
+		assert_eq!(frame.call_type, CallFrameType::Create);
+		assert_eq!(frame.to, Some(9.into()));
+		assert_eq!(frame.output, Some(vec![0x61].into()));
+	}
+
+	#[test]
+	fn should_report_revert_data_of_reverted_transaction() {
+		let traces = vec![FlatTrace {
+			action: call(1, 2, CallType::Call),
+			result: trace::Res::FailedCall(TraceError::Reverted),
+			subtraces: 0,
+			trace_address: vec![],
+		}];
+		let mut executed = executed(traces);
+		executed.exception = Some(vm::Error::Reverted);
+		executed.output = vec![0x08, 0xc3];
+
+		let frame = CallFrame::from_executed(executed).unwrap();
+		assert_eq!(frame.error, Some("execution reverted".into()));
+		assert_eq!(frame.output, Some(vec![0x08, 0xc3].into()));
+	}
+
+	#[test]
+	fn should_report_geth_error_messages() {
+		let errors = vec![
+			(TraceError::OutOfGas, "out of gas"),
+			(TraceError::BadJumpDestination, "invalid jump destination"),
+			(TraceError::BadInstruction, "invalid opcode"),
+			(TraceError::StackUnderflow, "stack underflow"),
+			(TraceError::OutOfStack, "stack limit reached"),
+			(TraceError::BuiltIn, "precompiled contract failed"),
+			(TraceError::Internal, "internal error"),
+			(TraceError::MutableCallInStaticContext, "write protection"),
+			(TraceError::Wasm, "wasm runtime error"),
+			(TraceError::OutOfBounds, "return data out of bounds"),
+			(TraceError::Reverted, "execution reverted"),
+		];
+
+		for (error, message) in errors {
+			let traces = vec![
+				FlatTrace {
+					action: call(1, 2, CallType::Call),
+					result: trace::Res::Call(trace::CallResult { gas_used: 0x500.into(), output: vec![] }),
+					subtraces: 1,
+					trace_address: vec![],
+				},
+				FlatTrace {
+					action: call(2, 3, CallType::Call),
+					result: trace::Res::FailedCall(error),
+					subtraces: 0,
+					trace_address: vec![0],
+				},
+			];
+
+			let frame = CallFrame::from_executed(executed(traces)).unwrap();
+			assert_eq!(frame.calls[0].error, Some(message.into()));
+			assert_eq!(frame.calls[0].gas_used, None);
+		}
+	}
+
+	#[test]
+	fn should_return_none_without_traces() {
+		assert_eq!(CallFrame::from_executed(executed(vec![])), None);
+	}
+}
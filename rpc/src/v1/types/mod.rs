@@ -23,6 +23,7 @@ mod account_info;
 mod block;
 mod block_number;
 mod bytes;
+mod call_frame;
 mod call_request;
 mod confirmations;
 mod consensus_status;
@@ -40,6 +41,7 @@ mod sync;
 mod trace;
 mod trace_filter;
 mod transaction;
+mod transaction_filter;
 mod transaction_request;
 mod transaction_condition;
 mod work;
@@ -53,6 +55,7 @@ pub use self::account_info::{AccountInfo, ExtAccountInfo, HwAccountInfo, EthAcco
 pub use self::bytes::Bytes;
 pub use self::block::{RichBlock, Block, BlockTransactions, Header, RichHeader, Rich};
 pub use self::block_number::{BlockNumber, LightBlockNumber, block_number_to_id};
+pub use self::call_frame::{CallFrame, CallFrameType, DebugTraceOptions, CALL_TRACER};
 pub use self::call_request::CallRequest;
 pub use self::confirmations::{
 	ConfirmationPayload, ConfirmationRequest, ConfirmationResponse, ConfirmationResponseWithToken,
@@ -76,6 +79,7 @@ pub use self::sync::{
 pub use self::trace::{LocalizedTrace, TraceResults, TraceResultsWithTransactionHash};
 pub use self::trace_filter::TraceFilter;
 pub use self::transaction::{Transaction, RichRawTransaction, LocalTransactionStatus};
+pub use self::transaction_filter::{TransactionFilter, ValueRange};
 pub use self::transaction_request::TransactionRequest;
 pub use self::transaction_condition::TransactionCondition;
 pub use self::work::Work;
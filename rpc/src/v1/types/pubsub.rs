@@ -20,10 +20,10 @@ use ethereum_types::H256;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde::de::Error;
 use serde_json::{Value, from_value};
-use v1::types::{RichHeader, Filter, Log};
+use v1::types::{RichHeader, Filter, Log, Transaction};
 
 /// Subscription result.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Result {
 	/// New block header.
 	Header(Box<RichHeader>),
@@ -31,6 +31,8 @@ pub enum Result {
 	Log(Box<Log>),
 	/// Transaction hash
 	TransactionHash(H256),
+	/// Full transaction
+	FullTransaction(Box<Transaction>),
 }
 
 impl Serialize for Result {
@@ -41,6 +43,7 @@ impl Serialize for Result {
 			Result::Header(ref header) => header.serialize(serializer),
 			Result::Log(ref log) => log.serialize(serializer),
 			Result::TransactionHash(ref hash) => hash.serialize(serializer),
+			Result::FullTransaction(ref transaction) => transaction.serialize(serializer),
 		}
 	}
 }
@@ -56,6 +59,8 @@ pub enum Kind {
 	Logs,
 	/// New Pending Transactions subscription.
 	NewPendingTransactions,
+	/// New Pending Transactions subscription, with full transaction objects.
+	PendingTransactions,
 	/// Node syncing status subscription.
 	Syncing,
 }
@@ -101,6 +106,7 @@ mod tests {
 		assert_eq!(serde_json::from_str::<Kind>(r#""newHeads""#).unwrap(), Kind::NewHeads);
 		assert_eq!(serde_json::from_str::<Kind>(r#""logs""#).unwrap(), Kind::Logs);
 		assert_eq!(serde_json::from_str::<Kind>(r#""newPendingTransactions""#).unwrap(), Kind::NewPendingTransactions);
+		assert_eq!(serde_json::from_str::<Kind>(r#""pendingTransactions""#).unwrap(), Kind::PendingTransactions);
 		assert_eq!(serde_json::from_str::<Kind>(r#""syncing""#).unwrap(), Kind::Syncing);
 	}
 
@@ -0,0 +1,94 @@
+// Copyright 2015-2019 Parity Technologies (UK) Ltd.
+// This file is part of Parity Ethereum.
+
+// Parity Ethereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Ethereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Ethereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Pending transaction filter deserialization.
+
+use ethereum_types::{H160, U256};
+use miner::pool;
+
+/// Inclusive range of values
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ValueRange {
+	/// Lower bound
+	pub min: Option<U256>,
+	/// Upper bound
+	pub max: Option<U256>,
+}
+
+impl Into<pool::ValueRange> for ValueRange {
+	fn into(self) -> pool::ValueRange {
+		pool::ValueRange {
+			min: self.min,
+			max: self.max,
+		}
+	}
+}
+
+/// Pending transaction filter
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionFilter {
+	/// Sender
+	pub from: Option<H160>,
+	/// Recipient
+	pub to: Option<H160>,
+	/// Gas price range
+	pub gas_price: Option<ValueRange>,
+	/// Value range
+	pub value: Option<ValueRange>,
+}
+
+impl Into<pool::TransactionFilter> for TransactionFilter {
+	fn into(self) -> pool::TransactionFilter {
+		pool::TransactionFilter {
+			from: self.from,
+			to: self.to,
+			gas_price: self.gas_price.map(Into::into).unwrap_or_default(),
+			value: self.value.map(Into::into).unwrap_or_default(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json;
+	use ethereum_types::H160;
+	use v1::types::{TransactionFilter, ValueRange};
+
+	#[test]
+	fn test_empty_transaction_filter_deserialize() {
+		let deserialized: TransactionFilter = serde_json::from_str(r#"{}"#).unwrap();
+		assert_eq!(deserialized, TransactionFilter::default());
+	}
+
+	#[test]
+	fn test_transaction_filter_deserialize() {
+		let s = r#"{
+			"from": "0x0000000000000000000000000000000000000003",
+			"gasPrice": { "min": "0x1", "max": "0x10" },
+			"value": { "min": "0x64" }
+		}"#;
+		let deserialized: TransactionFilter = serde_json::from_str(s).unwrap();
+		assert_eq!(deserialized, TransactionFilter {
+			from: Some(H160::from(3)),
+			to: None,
+			gas_price: Some(ValueRange { min: Some(1.into()), max: Some(16.into()) }),
+			value: Some(ValueRange { min: Some(100.into()), max: None }),
+		});
+	}
+}